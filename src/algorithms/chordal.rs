@@ -0,0 +1,195 @@
+//! Recognition of chordal graphs
+//!
+//! A graph is chordal if each cycle with more than three vertices has a chord, an edge between two vertices of the cycle that are not adjacent on the cycle. The edges are taken as undirected.
+use crate::{
+    algorithms::enumeration::basic::lexicographic::{undirected_neighbours, LexBreadthFirst},
+    graph::{Graph, VertexId},
+};
+
+/// Gives an order of all vertices in which the later neighbours of each vertex form a clique, or `None` if the graph is not chordal
+///
+/// Removing the vertices in this order therefore always removes a vertex whose remaining neighbours are pairwise adjacent.
+///
+/// The ordering is the reverse of the [`LexBreadthFirst`] order, which is checked in linear time.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{algorithms::chordal::perfect_elimination_ordering, Graph, VertexId};
+///
+/// // a triangle 0 - 1 - 2 with the pendant vertex 3 at 2
+/// let graph = Graph::from(4, vec![(0, 1), (1, 2), (2, 0), (2, 3)]).unwrap();
+///
+/// assert_eq!(
+///     perfect_elimination_ordering(&graph),
+///     Some(vec![VertexId(3), VertexId(2), VertexId(1), VertexId(0)])
+/// );
+/// ```
+pub fn perfect_elimination_ordering(graph: &Graph) -> Option<Vec<VertexId>> {
+    let order = LexBreadthFirst::on(graph)
+        .map(|v| v.0)
+        .collect::<Vec<usize>>();
+    eliminates_perfectly(&undirected_neighbours(graph), &order)
+        .then(|| order.into_iter().rev().map(VertexId).collect())
+}
+
+/// Checks whether each cycle with more than three vertices has a chord
+///
+/// # Examples
+///
+/// ```
+/// use graph::{is_chordal, Graph};
+///
+/// let square = Graph::from(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)]).unwrap();
+/// let square_with_diagonal = Graph::from(4, vec![(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]).unwrap();
+///
+/// assert!(!is_chordal(&square));
+/// assert!(is_chordal(&square_with_diagonal));
+/// ```
+pub fn is_chordal(graph: &Graph) -> bool {
+    perfect_elimination_ordering(graph).is_some()
+}
+
+// Checks that the reverse of the visit order is a perfect elimination ordering: The earlier
+// neighbours of each vertex, except the latest one, have to be neighbours of that latest one.
+// The requirements are collected per latest neighbour, such that each adjacency is only
+// looked at once.
+fn eliminates_perfectly(neighbours: &[Vec<usize>], order: &[usize]) -> bool {
+    let mut index = vec![0; order.len()];
+    for (position, &vertex) in order.iter().enumerate() {
+        index[vertex] = position;
+    }
+    let mut required = vec![Vec::<usize>::new(); order.len()];
+    for &vertex in order {
+        let earlier = neighbours[vertex]
+            .iter()
+            .filter(|&&neighbour| index[neighbour] < index[vertex]);
+        if let Some(&latest) = earlier.clone().max_by_key(|&&neighbour| index[neighbour]) {
+            required[latest].extend(earlier.filter(|&&neighbour| neighbour != latest));
+        }
+    }
+    let mut adjacent = vec![false; order.len()];
+    required.iter().enumerate().all(|(vertex, required)| {
+        neighbours[vertex].iter().for_each(|&n| adjacent[n] = true);
+        let fulfilled = required.iter().all(|&n| adjacent[n]);
+        neighbours[vertex].iter().for_each(|&n| adjacent[n] = false);
+        fulfilled
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{
+        algorithms::enumeration::basic::lexicographic::MaximumCardinalitySearch, random::Rng,
+    };
+
+    // Removes simplicial vertices, whose neighbours form a clique, as long as there are any
+    fn is_chordal_by_elimination(graph: &Graph) -> bool {
+        let mut neighbours = undirected_neighbours(graph)
+            .into_iter()
+            .map(HashSet::<usize>::from_iter)
+            .collect::<Vec<HashSet<usize>>>();
+        let mut remaining = (0..neighbours.len()).collect::<HashSet<usize>>();
+        while let Some(&simplicial) = remaining.iter().find(|&&v| {
+            neighbours[v].iter().all(|a| {
+                neighbours[v]
+                    .iter()
+                    .all(|b| a == b || neighbours[*a].contains(b))
+            })
+        }) {
+            remaining.remove(&simplicial);
+            for neighbour in neighbours[simplicial].clone() {
+                neighbours[neighbour].remove(&simplicial);
+            }
+        }
+        remaining.is_empty()
+    }
+
+    fn random_graph(rng: &mut Rng, vertex_count: usize, edge_count: usize) -> Graph {
+        let edges = (0..edge_count)
+            .map(|_| (rng.below(vertex_count), rng.below(vertex_count)))
+            .collect();
+        Graph::from(vertex_count, edges).unwrap()
+    }
+
+    #[test]
+    fn empty_graph_is_chordal() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        assert_eq!(perfect_elimination_ordering(&graph), Some(vec![]));
+    }
+
+    #[test]
+    fn forests_and_complete_graphs_are_chordal() {
+        let forest = Graph::from(6, vec![(0, 1), (0, 2), (2, 3), (4, 5)]).unwrap();
+        assert!(is_chordal(&forest));
+        let complete = Graph::from(
+            4,
+            (0..4)
+                .flat_map(|from| (0..4).map(move |to| (from, to)))
+                .collect(),
+        )
+        .unwrap();
+        assert!(is_chordal(&complete));
+    }
+
+    #[test]
+    fn cycles_without_chords_are_not_chordal() {
+        let cycle = Graph::from(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]).unwrap();
+        assert!(!is_chordal(&cycle));
+        // direction does not matter
+        let cycle = Graph::from(4, vec![(0, 1), (2, 1), (2, 3), (0, 3)]).unwrap();
+        assert!(!is_chordal(&cycle));
+    }
+
+    #[test]
+    fn later_neighbours_in_ordering_form_a_clique() {
+        let star = Graph::from(3, vec![(0, 1), (0, 2)]).unwrap();
+        assert_eq!(
+            perfect_elimination_ordering(&star),
+            Some(vec![VertexId(2), VertexId(1), VertexId(0)])
+        );
+        let mut rng = Rng::seed_from(9);
+        for _ in 0..300 {
+            let vertex_count = 1 + rng.below(8);
+            let edge_count = rng.below(3 * vertex_count);
+            let graph = random_graph(&mut rng, vertex_count, edge_count);
+            let Some(ordering) = perfect_elimination_ordering(&graph) else {
+                continue;
+            };
+            let neighbours = undirected_neighbours(&graph);
+            for (position, vertex) in ordering.iter().enumerate() {
+                let later = ordering[position + 1..]
+                    .iter()
+                    .filter(|v| neighbours[vertex.0].contains(&v.0))
+                    .collect::<Vec<&VertexId>>();
+                for a in &later {
+                    for b in later.iter().filter(|b| a != *b) {
+                        assert!(neighbours[a.0].contains(&b.0));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn agrees_with_elimination_of_simplicial_vertices() {
+        let mut rng = Rng::seed_from(3);
+        for _ in 0..300 {
+            let vertex_count = 1 + rng.below(8);
+            let edge_count = rng.below(2 * vertex_count);
+            let graph = random_graph(&mut rng, vertex_count, edge_count);
+            let chordal = is_chordal_by_elimination(&graph);
+            assert_eq!(is_chordal(&graph), chordal);
+            let mcs_order = MaximumCardinalitySearch::on(&graph)
+                .map(|v| v.0)
+                .collect::<Vec<usize>>();
+            assert_eq!(
+                eliminates_perfectly(&undirected_neighbours(&graph), &mcs_order),
+                chordal
+            );
+        }
+    }
+}
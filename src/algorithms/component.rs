@@ -1,7 +1,8 @@
 use crate::graph::VertexId;
-use std::collections::HashSet;
+use std::collections::{hash_set, HashSet};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Component(HashSet<VertexId>);
 impl Component {
     pub fn new() -> Self {
@@ -13,4 +14,194 @@ impl Component {
     pub fn add(&mut self, vertex: VertexId) {
         self.0.insert(vertex);
     }
+    /// Gives the vertices of the component in arbitrary order, see [`Component::sorted`] for a fixed order
+    pub fn iter(&self) -> impl Iterator<Item = &VertexId> {
+        self.0.iter()
+    }
+    /// Number of vertices in the component
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn contains(&self, vertex: &VertexId) -> bool {
+        self.0.contains(vertex)
+    }
+    /// Gives the vertices of the component sorted by id
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Component, VertexId};
+    ///
+    /// let component = Component::from(vec![VertexId(2), VertexId(0), VertexId(1)]);
+    ///
+    /// assert_eq!(component.sorted(), vec![&VertexId(0), &VertexId(1), &VertexId(2)]);
+    /// ```
+    pub fn sorted(&self) -> Vec<&VertexId> {
+        let mut vertices = self.0.iter().collect::<Vec<&VertexId>>();
+        vertices.sort();
+        vertices
+    }
+}
+impl Default for Component {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl IntoIterator for Component {
+    type Item = VertexId;
+    type IntoIter = hash_set::IntoIter<VertexId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<'a> IntoIterator for &'a Component {
+    type Item = &'a VertexId;
+    type IntoIter = hash_set::Iter<'a, VertexId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Components that partition the vertices of a graph, e.g. the strongly or weakly connected components
+///
+/// Each component is identified by its position in the order in which the components were given. The component of a vertex is looked up in constant time, because vertex ids are used as positions in an array.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Graph, Partition, VertexId, SCC};
+///
+/// let graph = Graph::from(3, vec![(0, 1), (1, 0), (1, 2)]).unwrap();
+/// let partition = SCC::on(&graph).collect::<Partition>();
+///
+/// assert_eq!(partition.len(), 2);
+/// assert_eq!(partition.component_of(&VertexId(2)), Some(0));
+/// assert!(partition.same_component(&VertexId(0), &VertexId(1)));
+/// assert!(partition.components()[1].contains(&VertexId(0)));
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Partition {
+    components: Vec<Component>,
+    // component index of each vertex, indexed by the vertex id
+    component_of: Vec<Option<usize>>,
+}
+impl Partition {
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+    /// Number of components
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+    /// Gives the position of the component that includes the vertex, or `None` if no component includes it
+    pub fn component_of(&self, vertex: &VertexId) -> Option<usize> {
+        self.component_of.get(vertex.0).copied().flatten()
+    }
+    /// Gives the component that includes the vertex
+    pub fn component(&self, vertex: &VertexId) -> Option<&Component> {
+        self.component_of(vertex)
+            .map(|index| &self.components[index])
+    }
+    /// Checks whether both vertices are in the same component
+    pub fn same_component(&self, first: &VertexId, second: &VertexId) -> bool {
+        self.component_of(first)
+            .is_some_and(|component| self.component_of(second) == Some(component))
+    }
+    pub fn into_components(self) -> Vec<Component> {
+        self.components
+    }
+}
+// A vertex that is part of several components belongs to the last one
+impl FromIterator<Component> for Partition {
+    fn from_iter<I: IntoIterator<Item = Component>>(components: I) -> Self {
+        let components = components.into_iter().collect::<Vec<Component>>();
+        let size = components
+            .iter()
+            .flat_map(Component::iter)
+            .map(|vertex| vertex.0 + 1)
+            .max()
+            .unwrap_or(0);
+        let mut component_of = vec![None; size];
+        for (index, component) in components.iter().enumerate() {
+            for vertex in component {
+                component_of[vertex.0] = Some(index);
+            }
+        }
+        Self {
+            components,
+            component_of,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn component_gives_its_vertices() {
+        let mut component = Component::new();
+        assert!(component.is_empty());
+        component.add(VertexId(3));
+        component.add(VertexId(1));
+        component.add(VertexId(3));
+        assert_eq!(component.len(), 2);
+        assert!(component.contains(&VertexId(1)));
+        assert!(!component.contains(&VertexId(0)));
+        assert_eq!(component.sorted(), vec![&VertexId(1), &VertexId(3)]);
+        let mut vertices = component.into_iter().collect::<Vec<VertexId>>();
+        vertices.sort();
+        assert_eq!(vertices, vec![VertexId(1), VertexId(3)]);
+    }
+
+    #[test]
+    fn partition_maps_vertices_to_components() {
+        let partition = [
+            Component::from(vec![VertexId(4), VertexId(0)]),
+            Component::from(vec![VertexId(2)]),
+        ]
+        .into_iter()
+        .collect::<Partition>();
+        assert_eq!(partition.len(), 2);
+        assert_eq!(partition.component_of(&VertexId(0)), Some(0));
+        assert_eq!(partition.component_of(&VertexId(2)), Some(1));
+        assert_eq!(partition.component_of(&VertexId(1)), None);
+        assert_eq!(partition.component_of(&VertexId(7)), None);
+        assert_eq!(
+            partition.component(&VertexId(4)),
+            Some(&Component::from(vec![VertexId(0), VertexId(4)]))
+        );
+        assert!(partition.same_component(&VertexId(0), &VertexId(4)));
+        assert!(!partition.same_component(&VertexId(0), &VertexId(2)));
+        assert!(!partition.same_component(&VertexId(1), &VertexId(1)));
+    }
+
+    #[test]
+    fn empty_partition_has_no_components() {
+        let partition = Vec::<Component>::new().into_iter().collect::<Partition>();
+        assert!(partition.is_empty());
+        assert_eq!(partition.component_of(&VertexId(0)), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_components() {
+        let component = Component::from(vec![VertexId(3), VertexId(0), VertexId(5)]);
+        let json = serde_json::to_string(&component).unwrap();
+        assert_eq!(serde_json::from_str::<Component>(&json).unwrap(), component);
+        let partition = [component, Component::from(vec![VertexId(1)])]
+            .into_iter()
+            .collect::<Partition>();
+        let json = serde_json::to_string(&partition).unwrap();
+        assert_eq!(serde_json::from_str::<Partition>(&json).unwrap(), partition);
+    }
 }
@@ -1,40 +1,252 @@
-use std::collections::VecDeque;
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    marker::PhantomData,
+};
 
-use crate::graph::VertexId;
+use crate::{graph::VertexId, random::Rng};
 
-pub trait Buffer<'a> {
-    fn new() -> Self;
-    fn start(vertex: &'a VertexId) -> Self;
-    fn push(&mut self, vertex: &'a VertexId);
-    fn pop(&mut self) -> Option<&'a VertexId>;
+// Each vertex is buffered together with its depth, the length of the path on which it was
+// found
+pub trait Buffer<'a>: Sized {
+    // all start vertices have depth zero, the first one is popped first
+    fn start(vertices: Vec<&'a VertexId>) -> Self {
+        Self::resume(vertices.into_iter().map(|v| (v, 0)).collect())
+    }
+    // the first entry is popped first
+    fn resume(entries: Vec<(&'a VertexId, usize)>) -> Self;
+    fn push(&mut self, vertex: &'a VertexId, depth: usize);
+    // the out neighbours of a vertex in the order in which they were found
+    fn push_all(&mut self, vertices: Vec<(&'a VertexId, usize)>) {
+        vertices
+            .into_iter()
+            .for_each(|(v, depth)| self.push(v, depth));
+    }
+    // vertices with the same depth are popped in the given order
+    fn push_in_order(&mut self, vertices: Vec<(&'a VertexId, usize)>);
+    fn pop(&mut self) -> Option<(&'a VertexId, usize)>;
+    // all entries in the order in which they would be popped
+    fn entries(&self) -> Vec<(&'a VertexId, usize)>;
+    // only buffers that shuffle have a random generator
+    fn random_state(&self) -> Option<[u64; 4]> {
+        None
+    }
+    fn restore_random_state(&mut self, _state: [u64; 4]) {}
 }
-pub struct Queue<'a>(VecDeque<&'a VertexId>);
+pub struct Queue<'a>(VecDeque<(&'a VertexId, usize)>);
 impl<'a> Buffer<'a> for Queue<'a> {
-    fn new() -> Self {
-        Self(VecDeque::new())
+    fn resume(entries: Vec<(&'a VertexId, usize)>) -> Self {
+        Self(entries.into_iter().rev().collect())
     }
-    fn start(vertex: &'a VertexId) -> Self {
-        Self(VecDeque::from(vec![vertex]))
+    fn push(&mut self, vertex: &'a VertexId, depth: usize) {
+        self.0.push_front((vertex, depth));
     }
-    fn push(&mut self, vertex: &'a VertexId) {
-        self.0.push_front(vertex);
+    fn push_in_order(&mut self, vertices: Vec<(&'a VertexId, usize)>) {
+        vertices
+            .into_iter()
+            .for_each(|(v, depth)| self.push(v, depth));
     }
-    fn pop(&mut self) -> Option<&'a VertexId> {
+    fn pop(&mut self) -> Option<(&'a VertexId, usize)> {
         self.0.pop_back()
     }
+    fn entries(&self) -> Vec<(&'a VertexId, usize)> {
+        self.0.iter().rev().copied().collect()
+    }
 }
-pub struct Stack<'a>(Vec<&'a VertexId>);
+pub struct Stack<'a>(Vec<(&'a VertexId, usize)>);
 impl<'a> Buffer<'a> for Stack<'a> {
-    fn new() -> Self {
-        Self(Vec::new())
+    fn resume(entries: Vec<(&'a VertexId, usize)>) -> Self {
+        Self(entries.into_iter().rev().collect())
     }
-    fn start(vertex: &'a VertexId) -> Self {
-        Self(vec![vertex])
+    fn push(&mut self, vertex: &'a VertexId, depth: usize) {
+        self.0.push((vertex, depth));
     }
-    fn push(&mut self, vertex: &'a VertexId) {
-        self.0.push(vertex);
+    fn push_in_order(&mut self, vertices: Vec<(&'a VertexId, usize)>) {
+        vertices
+            .into_iter()
+            .rev()
+            .for_each(|(v, depth)| self.push(v, depth));
     }
-    fn pop(&mut self) -> Option<&'a VertexId> {
+    fn pop(&mut self) -> Option<(&'a VertexId, usize)> {
         self.0.pop()
     }
+    fn entries(&self) -> Vec<(&'a VertexId, usize)> {
+        self.0.iter().rev().copied().collect()
+    }
+}
+
+// Shuffles the out neighbours of each vertex before they are pushed to the inner buffer. The
+// start vertices keep their order.
+pub struct Shuffled<'a, B> {
+    buffer: B,
+    rng: Rng,
+    marker: PhantomData<&'a VertexId>,
+}
+impl<'a, B> Shuffled<'a, B> {
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = Rng::seed_from(seed);
+    }
+}
+impl<'a, B> Buffer<'a> for Shuffled<'a, B>
+where
+    B: Buffer<'a>,
+{
+    fn resume(entries: Vec<(&'a VertexId, usize)>) -> Self {
+        Self {
+            buffer: B::resume(entries),
+            rng: Rng::seed_from(0),
+            marker: PhantomData,
+        }
+    }
+    fn push(&mut self, vertex: &'a VertexId, depth: usize) {
+        self.buffer.push(vertex, depth);
+    }
+    fn push_all(&mut self, mut vertices: Vec<(&'a VertexId, usize)>) {
+        self.rng.shuffle(&mut vertices);
+        self.buffer.push_all(vertices);
+    }
+    fn push_in_order(&mut self, vertices: Vec<(&'a VertexId, usize)>) {
+        self.push_all(vertices);
+    }
+    fn pop(&mut self) -> Option<(&'a VertexId, usize)> {
+        self.buffer.pop()
+    }
+    fn entries(&self) -> Vec<(&'a VertexId, usize)> {
+        self.buffer.entries()
+    }
+    fn random_state(&self) -> Option<[u64; 4]> {
+        Some(self.rng.state())
+    }
+    fn restore_random_state(&mut self, state: [u64; 4]) {
+        self.rng = Rng::from_state(state);
+    }
+}
+
+type Score<'a> = Box<dyn Fn(&VertexId, usize) -> f64 + 'a>;
+
+// Pops the vertex with the smallest score first, vertices with the same score in the
+// order in which they were pushed. Without a scoring function the depth is the score.
+pub struct PriorityBuffer<'a> {
+    heap: BinaryHeap<Prioritized<'a>>,
+    score: Option<Score<'a>>,
+    pushed: usize,
+}
+struct Prioritized<'a> {
+    score: f64,
+    sequence: usize,
+    vertex: &'a VertexId,
+    depth: usize,
+}
+impl<'a> Ord for Prioritized<'a> {
+    // the heap gives the largest entry first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .score
+            .total_cmp(&self.score)
+            .then(other.sequence.cmp(&self.sequence))
+    }
+}
+impl<'a> PartialOrd for Prioritized<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<'a> PartialEq for Prioritized<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<'a> Eq for Prioritized<'a> {}
+impl<'a> PriorityBuffer<'a> {
+    pub fn score_by(&mut self, score: impl Fn(&VertexId, usize) -> f64 + 'a) {
+        self.heap = std::mem::take(&mut self.heap)
+            .into_iter()
+            .map(|entry| Prioritized {
+                score: score(entry.vertex, entry.depth),
+                ..entry
+            })
+            .collect();
+        self.score = Some(Box::new(score));
+    }
+}
+impl<'a> Buffer<'a> for PriorityBuffer<'a> {
+    fn resume(entries: Vec<(&'a VertexId, usize)>) -> Self {
+        let mut buffer = Self {
+            heap: BinaryHeap::new(),
+            score: None,
+            pushed: 0,
+        };
+        buffer.push_all(entries);
+        buffer
+    }
+    fn push(&mut self, vertex: &'a VertexId, depth: usize) {
+        let score = match &self.score {
+            Some(score) => score(vertex, depth),
+            None => depth as f64,
+        };
+        self.heap.push(Prioritized {
+            score,
+            sequence: self.pushed,
+            vertex,
+            depth,
+        });
+        self.pushed += 1;
+    }
+    fn push_in_order(&mut self, vertices: Vec<(&'a VertexId, usize)>) {
+        vertices
+            .into_iter()
+            .for_each(|(v, depth)| self.push(v, depth));
+    }
+    fn pop(&mut self) -> Option<(&'a VertexId, usize)> {
+        self.heap.pop().map(|entry| (entry.vertex, entry.depth))
+    }
+    fn entries(&self) -> Vec<(&'a VertexId, usize)> {
+        let mut entries = self.heap.iter().collect::<Vec<&Prioritized>>();
+        entries.sort_by(|a, b| b.cmp(a));
+        entries
+            .into_iter()
+            .map(|entry| (entry.vertex, entry.depth))
+            .collect()
+    }
+}
+
+// Pops vertices by ascending depth if the depths of pushed vertices are at most one larger
+// than the depth of the last popped vertex, as in a 0-1 breadth first search: Vertices
+// with the same depth are pushed to the front, the others to the back.
+pub struct DequeBuffer<'a> {
+    deque: VecDeque<(&'a VertexId, usize)>,
+    depth: usize,
+}
+impl<'a> Buffer<'a> for DequeBuffer<'a> {
+    // the next pop sets the depth before anything is pushed
+    fn resume(entries: Vec<(&'a VertexId, usize)>) -> Self {
+        Self {
+            deque: entries.into_iter().collect(),
+            depth: 0,
+        }
+    }
+    fn push(&mut self, vertex: &'a VertexId, depth: usize) {
+        match depth <= self.depth {
+            true => self.deque.push_front((vertex, depth)),
+            false => self.deque.push_back((vertex, depth)),
+        }
+    }
+    fn push_in_order(&mut self, vertices: Vec<(&'a VertexId, usize)>) {
+        let (front, back): (Vec<_>, Vec<_>) = vertices
+            .into_iter()
+            .partition(|(_, depth)| *depth <= self.depth);
+        front
+            .into_iter()
+            .rev()
+            .for_each(|(v, depth)| self.push(v, depth));
+        back.into_iter().for_each(|(v, depth)| self.push(v, depth));
+    }
+    fn pop(&mut self) -> Option<(&'a VertexId, usize)> {
+        let (vertex, depth) = self.deque.pop_front()?;
+        self.depth = depth;
+        Some((vertex, depth))
+    }
+    fn entries(&self) -> Vec<(&'a VertexId, usize)> {
+        self.deque.iter().copied().collect()
+    }
 }
@@ -0,0 +1,156 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::graph::{Graph, VertexId};
+
+/// Iterative deepening depth first enumeration on a single tree
+///
+/// Runs a depth first search limited to depth 0, then to depth 1 and so on. In each run the vertices with a distance of exactly the limit are given. Therefore the vertices are given in the same order of distances as in a breadth first enumeration, and in contrast to a depth first enumeration with [`super::tree::Enumeration::max_depth`] no vertex within the maximum distance is missed. The price is that vertices near the start are traversed again in each run. The memory is not smaller than for a breadth first enumeration: All found vertices and the smallest depth of each vertex in the current run are kept, and the stack holds the out neighbours of all vertices on the current path, which is linear in the size of the graph.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Graph, IterativeDeepening, VertexId};
+///
+/// let graph = Graph::from(4, vec![(0, 1), (1, 2), (2, 3), (0, 2)]).unwrap();
+///
+/// assert_eq!(
+///     IterativeDeepening::on(&graph, &VertexId(0))
+///         .min_depth(1)
+///         .max_depth(2)
+///         .collect::<Vec<&VertexId>>(),
+///     vec![&VertexId(2), &VertexId(1), &VertexId(3)]
+/// );
+/// ```
+pub struct IterativeDeepening<'a> {
+    graph: &'a Graph,
+    start: &'a VertexId,
+    min_depth: usize,
+    max_depth: Option<usize>,
+    limit: usize,
+    stack: Vec<(&'a VertexId, usize)>,
+    // smallest depth with which a vertex was reached in the current run
+    reached: HashMap<&'a VertexId, usize>,
+    found: HashSet<&'a VertexId>,
+    found_in_run: bool,
+}
+impl<'a> IterativeDeepening<'a> {
+    pub fn on(graph: &'a Graph, start: &'a VertexId) -> Self {
+        Self {
+            graph,
+            start,
+            min_depth: 0,
+            max_depth: None,
+            limit: 0,
+            stack: match graph.contains(start) {
+                true => vec![(start, 0)],
+                false => vec![],
+            },
+            reached: HashMap::new(),
+            found: HashSet::new(),
+            found_in_run: false,
+        }
+    }
+    /// Does not give vertices with a distance smaller than `depth`, see [`super::tree::Enumeration::min_depth`]
+    pub fn min_depth(self, depth: usize) -> Self {
+        Self {
+            min_depth: depth,
+            ..self
+        }
+    }
+    /// Stops after the run that is limited to `depth`
+    pub fn max_depth(self, depth: usize) -> Self {
+        Self {
+            max_depth: Some(depth),
+            ..self
+        }
+    }
+
+    // If no vertex has a distance of the current limit, there are no vertices with larger
+    // distances either
+    fn start_next_run(&mut self) -> bool {
+        if !self.found_in_run
+            || self
+                .max_depth
+                .is_some_and(|max_depth| self.limit >= max_depth)
+        {
+            return false;
+        }
+        self.limit += 1;
+        self.stack.push((self.start, 0));
+        self.reached.clear();
+        self.found_in_run = false;
+        true
+    }
+}
+
+impl<'a> Iterator for IterativeDeepening<'a> {
+    type Item = &'a VertexId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((vertex, depth)) = self.stack.pop() else {
+                if self.start_next_run() {
+                    continue;
+                }
+                return None;
+            };
+            // a vertex is traversed again if it is reached via a shorter path
+            if self.reached.get(vertex).is_some_and(|&d| d <= depth) {
+                continue;
+            }
+            self.reached.insert(vertex, depth);
+            if depth < self.limit {
+                self.graph
+                    .out_neighbors_ref(vertex)
+                    .for_each(|v| self.stack.push((v, depth + 1)));
+            } else if self.found.insert(vertex) {
+                self.found_in_run = true;
+                if depth >= self.min_depth {
+                    return Some(vertex);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_not_find_non_existend_vertex() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        assert_eq!(
+            IterativeDeepening::on(&graph, &VertexId(0)).collect::<Vec<&VertexId>>(),
+            Vec::<&VertexId>::new()
+        );
+    }
+
+    #[test]
+    fn enumerates_vertices_by_distance() {
+        let graph = Graph::from(6, vec![(0, 1), (1, 2), (2, 3), (3, 4), (0, 3), (4, 0)]).unwrap();
+        assert_eq!(
+            IterativeDeepening::on(&graph, &VertexId(0)).collect::<Vec<&VertexId>>(),
+            vec![
+                &VertexId(0),
+                &VertexId(3),
+                &VertexId(1),
+                &VertexId(4),
+                &VertexId(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_vertex_first_reached_on_a_longer_path_with_its_distance() {
+        // depth first reaches 2 via 1 before it finds the direct edge
+        let graph = Graph::from(4, vec![(0, 2), (0, 1), (1, 2), (2, 3)]).unwrap();
+        assert_eq!(
+            IterativeDeepening::on(&graph, &VertexId(0))
+                .min_depth(2)
+                .max_depth(2)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(3)]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::graph::{Graph, VertexId};
+
+/// A vertex found by [`Distances`] together with the start vertex that reached it first
+#[derive(Debug, PartialEq, Clone)]
+pub struct Reached<'a> {
+    pub vertex: &'a VertexId,
+    /// Number of edges on the shortest path from the source
+    pub distance: usize,
+    pub source: &'a VertexId,
+}
+
+/// Breadth first enumeration from several start vertices that gives the distance of each vertex to the nearest start vertex
+///
+/// Each vertex is reached first by the start vertex with the smallest distance to it. If several start vertices have the same distance, the one that is given first wins.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Distances, Graph, Reached, VertexId};
+///
+/// // 0 -> 1 -> 2 <- 3
+/// let graph = Graph::from(4, vec![(0, 1), (1, 2), (3, 2)]).unwrap();
+/// let sources = [VertexId(0), VertexId(3)];
+///
+/// assert_eq!(
+///     Distances::on(&graph, &sources).last(),
+///     Some(Reached { vertex: &VertexId(2), distance: 1, source: &VertexId(3) })
+/// );
+/// ```
+pub struct Distances<'a> {
+    graph: &'a Graph,
+    queue: VecDeque<Reached<'a>>,
+    found: HashSet<&'a VertexId>,
+}
+impl<'a> Distances<'a> {
+    pub fn on(graph: &'a Graph, sources: impl IntoIterator<Item = &'a VertexId>) -> Self {
+        let mut distances = Self {
+            graph,
+            queue: VecDeque::new(),
+            found: HashSet::new(),
+        };
+        for source in sources.into_iter().filter(|v| graph.contains(v)) {
+            distances.find(Reached {
+                vertex: source,
+                distance: 0,
+                source,
+            });
+        }
+        distances
+    }
+
+    // A vertex is marked as found when it is queued, such that the first source that
+    // reaches it keeps it
+    fn find(&mut self, reached: Reached<'a>) {
+        if self.found.insert(reached.vertex) {
+            self.queue.push_back(reached);
+        }
+    }
+}
+
+impl<'a> Iterator for Distances<'a> {
+    type Item = Reached<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reached = self.queue.pop_front()?;
+        for neighbor in self.graph.out_neighbors_ref(reached.vertex) {
+            self.find(Reached {
+                vertex: neighbor,
+                distance: reached.distance + 1,
+                source: reached.source,
+            });
+        }
+        Some(reached)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(distances: Distances) -> Vec<(usize, usize, usize)> {
+        distances
+            .map(|reached| (reached.vertex.0, reached.distance, reached.source.0))
+            .collect()
+    }
+
+    #[test]
+    fn ignores_non_existent_sources() {
+        let graph = Graph::from(1, vec![]).unwrap();
+        assert_eq!(
+            found(Distances::on(&graph, &[VertexId(1), VertexId(0)])),
+            vec![(0, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn reaches_each_vertex_from_nearest_source() {
+        // 0 -> 1 -> 2 -> 3 -> 4 <- 5
+        let graph = Graph::from(6, vec![(0, 1), (1, 2), (2, 3), (3, 4), (5, 4)]).unwrap();
+        assert_eq!(
+            found(Distances::on(&graph, &[VertexId(0), VertexId(5)])),
+            vec![
+                (0, 0, 0),
+                (5, 0, 5),
+                (1, 1, 0),
+                (4, 1, 5),
+                (2, 2, 0),
+                (3, 3, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn first_source_wins_on_equal_distance() {
+        let graph = Graph::from(3, vec![(0, 2), (1, 2)]).unwrap();
+        assert_eq!(
+            found(Distances::on(&graph, &[VertexId(1), VertexId(0)])),
+            vec![(1, 0, 1), (0, 0, 0), (2, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn source_reached_by_another_source_keeps_distance_zero() {
+        let graph = Graph::from(2, vec![(0, 1)]).unwrap();
+        assert_eq!(
+            found(Distances::on(&graph, &[VertexId(0), VertexId(1)])),
+            vec![(0, 0, 0), (1, 0, 1)]
+        );
+    }
+}
@@ -25,7 +25,7 @@ where
             },
             Some(v) => Self {
                 graph,
-                enumeration: tree::Enumeration::on(graph, &v),
+                enumeration: tree::Enumeration::on(graph, v),
                 vertices: Box::new(vertices),
                 explored: HashSet::new(),
             },
@@ -58,7 +58,7 @@ where
             }
             None => match self.vertices.next() {
                 Some(v) => {
-                    self.start_new_tree(&v);
+                    self.start_new_tree(v);
                     self.next()
                 }
                 None => None,
@@ -70,6 +70,7 @@ pub type BreadthFirst<'a> = Enumeration<'a, buffer::Queue<'a>>;
 pub type DepthFirst<'a> = Enumeration<'a, buffer::Stack<'a>>;
 
 #[cfg(test)]
+#[allow(clippy::useless_conversion)]
 mod tests {
     use super::*;
 
@@ -0,0 +1,322 @@
+use crate::graph::{Graph, VertexId};
+
+// Neighbours of each vertex if the edges are taken as undirected, without loops and without
+// repeated neighbours
+pub(crate) fn undirected_neighbours(graph: &Graph) -> Vec<Vec<usize>> {
+    let mut neighbours = vec![Vec::new(); graph.vertices().count()];
+    for edge in graph.edges().filter(|edge| edge.0 != edge.1) {
+        neighbours[edge.0 .0].push(edge.1 .0);
+        neighbours[edge.1 .0].push(edge.0 .0);
+    }
+    for list in neighbours.iter_mut() {
+        list.sort_unstable();
+        list.dedup();
+    }
+    neighbours
+}
+
+// A part of the list of unvisited vertices, all vertices in it have the same label
+struct Class {
+    head: usize,
+    size: usize,
+    // class that was split off in front of this class while visiting the current vertex
+    split: Option<usize>,
+}
+
+/// Lexicographic breadth first enumeration on the full graph, where the edges are taken as undirected
+///
+/// Each vertex gets the label of the positions of its visited neighbours, the vertex with the lexicographically largest label is visited next. Ties are broken by the vertex id, therefore the enumeration starts with the first vertex. The vertices are visited like in a breadth first enumeration, but among the neighbours of a vertex the ones are preferred that are also neighbours of earlier vertices. The reverse order is a perfect elimination ordering if and only if the graph is chordal, see [`crate::algorithms::chordal`].
+///
+/// The partition refinement takes linear time in the size of the graph.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Graph, LexBreadthFirstOnGraph, VertexId};
+///
+/// // 3 is a neighbour of 0 and 1, whereas 2 is only a neighbour of 0
+/// let graph = Graph::from(4, vec![(0, 1), (0, 2), (0, 3), (1, 3)]).unwrap();
+///
+/// assert_eq!(
+///     LexBreadthFirstOnGraph::on(&graph).collect::<Vec<&VertexId>>(),
+///     vec![&VertexId(0), &VertexId(1), &VertexId(3), &VertexId(2)]
+/// );
+/// ```
+pub struct LexBreadthFirst<'a> {
+    vertices: Vec<&'a VertexId>,
+    neighbours: Vec<Vec<usize>>,
+    // unvisited vertices as a linked list, sorted by their class and within a class by id
+    first: Option<usize>,
+    previous: Vec<Option<usize>>,
+    next: Vec<Option<usize>>,
+    visited: Vec<bool>,
+    class_of: Vec<usize>,
+    classes: Vec<Class>,
+}
+impl<'a> LexBreadthFirst<'a> {
+    pub fn on(graph: &'a Graph) -> Self {
+        let vertices = graph.vertices().collect::<Vec<&VertexId>>();
+        let count = vertices.len();
+        Self {
+            neighbours: undirected_neighbours(graph),
+            first: (count > 0).then_some(0),
+            previous: (0..count).map(|v| v.checked_sub(1)).collect(),
+            next: (1..=count).map(|v| (v < count).then_some(v)).collect(),
+            visited: vec![false; count],
+            class_of: vec![0; count],
+            classes: vec![Class {
+                head: 0,
+                size: count,
+                split: None,
+            }],
+            vertices,
+        }
+    }
+
+    // Moves each unvisited neighbour into a new class directly in front of its old class. The
+    // neighbours are sorted by id and always appended to the new class, such that both classes
+    // stay sorted.
+    fn refine(&mut self, vertex: usize) {
+        let mut touched = Vec::new();
+        for index in 0..self.neighbours[vertex].len() {
+            let neighbour = self.neighbours[vertex][index];
+            if self.visited[neighbour] {
+                continue;
+            }
+            let class = self.class_of[neighbour];
+            let split = match self.classes[class].split {
+                Some(split) => split,
+                None => {
+                    self.classes.push(Class {
+                        head: neighbour,
+                        size: 0,
+                        split: None,
+                    });
+                    let split = self.classes.len() - 1;
+                    self.classes[class].split = Some(split);
+                    touched.push(class);
+                    split
+                }
+            };
+            let head = self.classes[class].head;
+            if neighbour == head {
+                if let Some(next) = self.next[neighbour] {
+                    self.classes[class].head = next;
+                }
+            } else {
+                self.unlink(neighbour);
+                self.insert_before(neighbour, head);
+            }
+            self.classes[split].size += 1;
+            self.classes[class].size -= 1;
+            self.class_of[neighbour] = split;
+        }
+        for class in touched {
+            self.classes[class].split = None;
+        }
+    }
+    fn unlink(&mut self, vertex: usize) {
+        let (previous, next) = (self.previous[vertex], self.next[vertex]);
+        match previous {
+            Some(previous) => self.next[previous] = next,
+            None => self.first = next,
+        }
+        if let Some(next) = next {
+            self.previous[next] = previous;
+        }
+    }
+    fn insert_before(&mut self, vertex: usize, position: usize) {
+        let previous = self.previous[position];
+        self.previous[vertex] = previous;
+        self.next[vertex] = Some(position);
+        self.previous[position] = Some(vertex);
+        match previous {
+            Some(previous) => self.next[previous] = Some(vertex),
+            None => self.first = Some(vertex),
+        }
+    }
+}
+
+impl<'a> Iterator for LexBreadthFirst<'a> {
+    type Item = &'a VertexId;
+
+    // the next vertex is always the head of the first non-empty class
+    fn next(&mut self) -> Option<Self::Item> {
+        let vertex = self.first?;
+        let class = self.class_of[vertex];
+        self.classes[class].size -= 1;
+        if let Some(next) = self.next[vertex] {
+            self.classes[class].head = next;
+        }
+        self.unlink(vertex);
+        self.visited[vertex] = true;
+        self.refine(vertex);
+        Some(self.vertices[vertex])
+    }
+}
+
+/// Maximum cardinality search on the full graph, where the edges are taken as undirected
+///
+/// The next vertex is always the one with the most visited neighbours. Like with [`LexBreadthFirst`], the reverse order is a perfect elimination ordering if and only if the graph is chordal.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Graph, MaximumCardinalitySearchOnGraph, VertexId};
+///
+/// let graph = Graph::from(4, vec![(0, 1), (1, 2), (2, 3), (3, 1)]).unwrap();
+///
+/// assert_eq!(
+///     MaximumCardinalitySearchOnGraph::on(&graph).collect::<Vec<&VertexId>>(),
+///     vec![&VertexId(0), &VertexId(1), &VertexId(3), &VertexId(2)]
+/// );
+/// ```
+pub struct MaximumCardinalitySearch<'a> {
+    vertices: Vec<&'a VertexId>,
+    neighbours: Vec<Vec<usize>>,
+    visited_neighbours: Vec<usize>,
+    visited: Vec<bool>,
+    // unvisited vertices by their number of visited neighbours, an entry is outdated if
+    // the vertex was visited or has more visited neighbours in the meantime
+    buckets: Vec<Vec<usize>>,
+    highest: usize,
+}
+impl<'a> MaximumCardinalitySearch<'a> {
+    pub fn on(graph: &'a Graph) -> Self {
+        let vertices = graph.vertices().collect::<Vec<&VertexId>>();
+        let count = vertices.len();
+        Self {
+            neighbours: undirected_neighbours(graph),
+            visited_neighbours: vec![0; count],
+            visited: vec![false; count],
+            buckets: vec![(0..count).rev().collect()],
+            highest: 0,
+            vertices,
+        }
+    }
+
+    fn pop_highest(&mut self) -> Option<usize> {
+        loop {
+            match self.buckets[self.highest].pop() {
+                Some(vertex)
+                    if !self.visited[vertex] && self.visited_neighbours[vertex] == self.highest =>
+                {
+                    return Some(vertex)
+                }
+                Some(_) => (),
+                None if self.highest == 0 => return None,
+                None => self.highest -= 1,
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for MaximumCardinalitySearch<'a> {
+    type Item = &'a VertexId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let vertex = self.pop_highest()?;
+        self.visited[vertex] = true;
+        for &neighbour in &self.neighbours[vertex] {
+            if self.visited[neighbour] {
+                continue;
+            }
+            self.visited_neighbours[neighbour] += 1;
+            let count = self.visited_neighbours[neighbour];
+            if self.buckets.len() <= count {
+                self.buckets.push(Vec::new());
+            }
+            self.buckets[count].push(neighbour);
+            self.highest = self.highest.max(count);
+        }
+        Some(self.vertices[vertex])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+
+    fn lex_order(graph: &Graph) -> Vec<usize> {
+        LexBreadthFirst::on(graph).map(|v| v.0).collect()
+    }
+    fn mcs_order(graph: &Graph) -> Vec<usize> {
+        MaximumCardinalitySearch::on(graph).map(|v| v.0).collect()
+    }
+
+    #[test]
+    fn does_not_find_anything_on_empty_graph() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        assert_eq!(lex_order(&graph), Vec::<usize>::new());
+        assert_eq!(mcs_order(&graph), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn visits_all_vertices_of_all_components() {
+        let graph = Graph::from(5, vec![(3, 4), (0, 2)]).unwrap();
+        assert_eq!(lex_order(&graph), vec![0, 2, 1, 3, 4]);
+        assert_eq!(mcs_order(&graph), vec![0, 2, 1, 3, 4]);
+    }
+
+    #[test]
+    fn takes_edges_as_undirected() {
+        let graph = Graph::from(3, vec![(1, 0), (2, 1), (1, 2), (0, 0)]).unwrap();
+        assert_eq!(lex_order(&graph), vec![0, 1, 2]);
+        assert_eq!(mcs_order(&graph), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn breaks_ties_by_vertex_id() {
+        let graph = Graph::from(4, vec![(0, 3)]).unwrap();
+        assert_eq!(lex_order(&graph), vec![0, 3, 1, 2]);
+        // 5 and 3 are both only neighbours of 0, 4 is a neighbour of 0 and 1
+        let graph = Graph::from(7, vec![(0, 5), (0, 3), (0, 1), (0, 4), (1, 4), (6, 2)]).unwrap();
+        assert_eq!(lex_order(&graph), vec![0, 1, 4, 3, 5, 2, 6]);
+    }
+
+    #[test]
+    fn matches_naive_lexicographic_order() {
+        // the label of a vertex has larger entries for earlier visited neighbours
+        let naive = |graph: &Graph| {
+            let neighbours = undirected_neighbours(graph);
+            let count = neighbours.len();
+            let mut labels = vec![Vec::new(); count];
+            let mut unvisited = (0..count).collect::<Vec<usize>>();
+            let mut order = Vec::new();
+            while !unvisited.is_empty() {
+                let index = (0..unvisited.len())
+                    .max_by(|&a, &b| {
+                        labels[unvisited[a]]
+                            .cmp(&labels[unvisited[b]])
+                            .then(unvisited[b].cmp(&unvisited[a]))
+                    })
+                    .unwrap();
+                let vertex = unvisited.remove(index);
+                for &neighbour in &neighbours[vertex] {
+                    labels[neighbour].push(count - order.len());
+                }
+                order.push(vertex);
+            }
+            order
+        };
+        let mut rng = Rng::seed_from(42);
+        for _ in 0..200 {
+            let vertex_count = 1 + rng.below(10);
+            let edges = (0..rng.below(2 * vertex_count))
+                .map(|_| (rng.below(vertex_count), rng.below(vertex_count)))
+                .collect();
+            let graph = Graph::from(vertex_count, edges).unwrap();
+            assert_eq!(lex_order(&graph), naive(&graph));
+        }
+    }
+
+    #[test]
+    fn prefers_neighbours_of_earlier_vertices() {
+        // 0 - 1, 0 - 2, 1 - 3, 2 - 4, 1 - 4
+        let graph = Graph::from(5, vec![(0, 1), (0, 2), (1, 3), (2, 4), (1, 4)]).unwrap();
+        // a breadth first enumeration would visit 3 before 4
+        assert_eq!(lex_order(&graph), vec![0, 1, 2, 4, 3]);
+        assert_eq!(mcs_order(&graph), vec![0, 2, 4, 1, 3]);
+    }
+}
@@ -1,3 +1,6 @@
 mod buffer;
+pub mod deepening;
+pub mod distances;
 pub mod graph;
+pub mod lexicographic;
 pub mod tree;
@@ -1,35 +1,320 @@
 use std::collections::HashSet;
 
 use super::buffer;
-use crate::graph::{Graph, VertexId};
+use crate::{
+    algorithms::enumeration::{
+        filter::{Filter, RejectedVertex},
+        order::NeighborOrder,
+    },
+    graph::{Edge, Graph, VertexId},
+};
+
+type Length<'a> = Box<dyn Fn(usize, &Edge) -> usize + 'a>;
+
+/// State of a basic enumeration, from which the enumeration can be resumed
+///
+/// The checkpoint only consists of vertices, with the `serde` feature it can be serialized. Depth limits, filters, a neighbour order, edge lengths and scores are not part of the checkpoint, they have to be given again to the resumed enumeration.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint {
+    /// Buffered vertices together with their depth, in the order in which they are popped
+    pub buffered: Vec<(VertexId, usize)>,
+    /// Explored vertices sorted by id
+    pub explored: Vec<VertexId>,
+    /// State of the random generator of a random enumeration
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub random_state: Option<[u64; 4]>,
+}
 
 pub struct Enumeration<'a, E> {
     graph: &'a Graph,
     next: E,
     explored: HashSet<&'a VertexId>,
+    min_depth: usize,
+    max_depth: Option<usize>,
+    filter: Filter<'a>,
+    order: Option<NeighborOrder<'a>>,
+    length: Option<Length<'a>>,
 }
 impl<'a, E> Enumeration<'a, E>
 where
     E: buffer::Buffer<'a>,
 {
     pub fn on(graph: &'a Graph, start: &'a VertexId) -> Self {
-        if graph.contains(&start) {
-            Self {
-                graph,
-                next: E::start(start),
-                explored: HashSet::new(),
-            }
-        } else {
-            Self {
-                graph,
-                next: E::new(),
-                explored: HashSet::new(),
-            }
+        Self::on_all(graph, [start])
+    }
+    /// Starts the enumeration at several vertices at once
+    ///
+    /// All start vertices have depth zero: A breadth first enumeration gives all start vertices first and then their neighbours, a depth first enumeration completely traverses the tree of the first start vertex before it continues with the next one that was not found yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{BreadthFirstOnTree, DepthFirstOnTree, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(4, vec![(0, 1), (2, 3)]).unwrap();
+    /// let starts = [VertexId(0), VertexId(2)];
+    ///
+    /// assert_eq!(
+    ///     BreadthFirstOnTree::on_all(&graph, &starts).collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(0), &VertexId(2), &VertexId(1), &VertexId(3)]
+    /// );
+    /// assert_eq!(
+    ///     DepthFirstOnTree::on_all(&graph, &starts).collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(0), &VertexId(1), &VertexId(2), &VertexId(3)]
+    /// );
+    /// ```
+    pub fn on_all(graph: &'a Graph, starts: impl IntoIterator<Item = &'a VertexId>) -> Self {
+        Self {
+            graph,
+            next: E::start(starts.into_iter().filter(|v| graph.contains(v)).collect()),
+            explored: HashSet::new(),
+            min_depth: 0,
+            max_depth: None,
+            filter: Filter::new(),
+            order: None,
+            length: None,
+        }
+    }
+    /// Does not give vertices with a depth smaller than `depth`
+    ///
+    /// These vertices are still traversed, such that vertices behind them can be found. The depth of a vertex is the number of edges on the path on which it was found: In a breadth first enumeration this is the distance from the start vertex, in a depth first enumeration it depends on the order in which the vertices are traversed, see [`super::deepening::IterativeDeepening`] for a depth first alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{BreadthFirstOnTree, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (0, 2)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     BreadthFirstOnTree::on(&graph, &VertexId(0))
+    ///         .min_depth(2)
+    ///         .max_depth(3)
+    ///         .collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(3), &VertexId(4)]
+    /// );
+    /// ```
+    pub fn min_depth(self, depth: usize) -> Self {
+        Self {
+            min_depth: depth,
+            ..self
+        }
+    }
+    /// Does not follow the out edges of vertices with depth `depth`
+    ///
+    /// Therefore only the part of the graph up to this depth is traversed. Each vertex is only traversed once, with the depth on which it was found first. In a breadth first enumeration this is its distance, such that all vertices up to the given distance are found. In a depth first enumeration a vertex that is first found on a long path is cut off there, even if it could be reached on a shorter path, therefore vertices behind it can be missed. [`super::deepening::IterativeDeepening`] finds all vertices up to the maximum distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{DepthFirstOnTree, Graph, IterativeDeepening, VertexId};
+    ///
+    /// // the path 0 -> 1 -> 2 -> 3 -> 4 with the shortcut 0 -> 3
+    /// let graph = Graph::from(5, vec![(0, 3), (0, 1), (1, 2), (2, 3), (3, 4)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     DepthFirstOnTree::on(&graph, &VertexId(0))
+    ///         .max_depth(3)
+    ///         .collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(0), &VertexId(1), &VertexId(2), &VertexId(3)]
+    /// );
+    /// assert_eq!(
+    ///     IterativeDeepening::on(&graph, &VertexId(0))
+    ///         .max_depth(3)
+    ///         .count(),
+    ///     5
+    /// );
+    /// ```
+    pub fn max_depth(self, depth: usize) -> Self {
+        Self {
+            max_depth: Some(depth),
+            ..self
+        }
+    }
+    /// Only enumerates vertices that fulfill the `predicate` completely
+    ///
+    /// What happens with the other vertices is defined by `rejected`. The predicate is also applied to the start vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{DepthFirstOnTree, Graph, RejectedVertex, VertexId};
+    ///
+    /// let graph = Graph::from(3, vec![(0, 1), (1, 2)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     DepthFirstOnTree::on(&graph, &VertexId(0))
+    ///         .filter_vertices(|v| *v != VertexId(1), RejectedVertex::NotEmitted)
+    ///         .collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(0), &VertexId(2)]
+    /// );
+    /// assert_eq!(
+    ///     DepthFirstOnTree::on(&graph, &VertexId(0))
+    ///         .filter_vertices(|v| *v != VertexId(1), RejectedVertex::NotExpanded)
+    ///         .collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(0), &VertexId(1)]
+    /// );
+    /// ```
+    pub fn filter_vertices(
+        self,
+        predicate: impl Fn(&VertexId) -> bool + 'a,
+        rejected: RejectedVertex,
+    ) -> Self {
+        Self {
+            filter: self.filter.with_vertices(predicate, rejected),
+            ..self
+        }
+    }
+    /// Only follows edges that fulfill the `predicate`
+    ///
+    /// The enumeration behaves as if the other edges were not part of the graph. A predicate on the target vertex of an edge removes vertices from the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{BreadthFirstOnTree, Edge, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(3, vec![(0, 1), (1, 2), (0, 2)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     BreadthFirstOnTree::on(&graph, &VertexId(0))
+    ///         .filter_edges(|Edge(_, to)| *to != VertexId(1))
+    ///         .collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(0), &VertexId(2)]
+    /// );
+    /// ```
+    pub fn filter_edges(self, predicate: impl Fn(&Edge) -> bool + 'a) -> Self {
+        Self {
+            filter: self.filter.with_edges(predicate),
+            ..self
+        }
+    }
+    /// Visits the out neighbours of each vertex in the given order, see [`NeighborOrder`]
+    pub fn neighbor_order(self, order: NeighborOrder<'a>) -> Self {
+        Self {
+            order: Some(order),
+            ..self
+        }
+    }
+    /// Gives each followed edge a length, the depth of a vertex is then the length of the path on which it was found
+    ///
+    /// The length function gets the position of an edge in the graph, as given by [`Graph::out_edges`], and the edge itself. Without a length function each edge has length one. Together with [`ZeroOneBreadthFirst`] and edges of length zero or one this gives the shortest distances from the start vertex, together with [`BestFirst`] and arbitrary lengths the search of Dijkstra.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Graph, VertexId, ZeroOneBreadthFirstOnTree};
+    ///
+    /// // the edge 0 -> 2 costs nothing
+    /// let graph = Graph::from(3, vec![(0, 1), (1, 2), (0, 2)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     ZeroOneBreadthFirstOnTree::on(&graph, &VertexId(0))
+    ///         .edge_lengths(|_, edge| usize::from(edge.1 != VertexId(2)))
+    ///         .collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(0), &VertexId(2), &VertexId(1)]
+    /// );
+    /// ```
+    pub fn edge_lengths(self, length: impl Fn(usize, &Edge) -> usize + 'a) -> Self {
+        Self {
+            length: Some(Box::new(length)),
+            ..self
         }
     }
     pub fn explored(self) -> HashSet<&'a VertexId> {
         self.explored
     }
+
+    /// Gives the current state of the enumeration, see [`Checkpoint`]
+    pub fn checkpoint(&self) -> Checkpoint {
+        let mut explored = self
+            .explored
+            .iter()
+            .map(|&v| v.clone())
+            .collect::<Vec<VertexId>>();
+        explored.sort();
+        Checkpoint {
+            buffered: self
+                .next
+                .entries()
+                .into_iter()
+                .map(|(v, depth)| (v.clone(), depth))
+                .collect(),
+            explored,
+            random_state: self.next.random_state(),
+        }
+    }
+    /// Continues an enumeration exactly where the enumeration stopped whose [`Checkpoint`] is given
+    ///
+    /// The checkpoint has to be taken on the same graph, vertices that are not part of the graph are ignored. A random enumeration continues with the state of its random generator, therefore it must not be seeded again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{DepthFirstOnTree, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(4, vec![(0, 1), (1, 2), (0, 3)]).unwrap();
+    /// let mut enumeration = DepthFirstOnTree::on(&graph, &VertexId(0));
+    /// let mut vertices = enumeration.by_ref().take(2).collect::<Vec<&VertexId>>();
+    /// let checkpoint = enumeration.checkpoint();
+    ///
+    /// vertices.extend(DepthFirstOnTree::resume(&graph, checkpoint));
+    ///
+    /// assert_eq!(
+    ///     vertices,
+    ///     DepthFirstOnTree::on(&graph, &VertexId(0)).collect::<Vec<&VertexId>>()
+    /// );
+    /// ```
+    pub fn resume(graph: &'a Graph, checkpoint: Checkpoint) -> Self {
+        let enumeration = Self::on_all(graph, []);
+        let mut next = E::resume(
+            checkpoint
+                .buffered
+                .iter()
+                .filter_map(|(v, depth)| Some((graph.vertex(v)?, *depth)))
+                .collect(),
+        );
+        if let Some(state) = checkpoint.random_state {
+            next.restore_random_state(state);
+        }
+        Self {
+            next,
+            explored: checkpoint
+                .explored
+                .iter()
+                .filter_map(|v| graph.vertex(v))
+                .collect(),
+            ..enumeration
+        }
+    }
+}
+
+impl<'a, E> Enumeration<'a, E>
+where
+    E: buffer::Buffer<'a>,
+{
+    fn expand(&mut self, vertex: &'a VertexId, depth: usize) {
+        let neighbors = self
+            .graph
+            .out_edges(vertex)
+            .filter(|(_, edge)| self.filter.follows(edge))
+            .map(|(position, edge)| {
+                let length = self
+                    .length
+                    .as_ref()
+                    .map_or(1, |length| length(position, edge));
+                (&edge.1, depth + length)
+            });
+        match &self.order {
+            None => self.next.push_all(neighbors.collect()),
+            Some(order) => {
+                let mut neighbors = neighbors.collect::<Vec<(&VertexId, usize)>>();
+                order.arrange_by(&mut neighbors, |(v, _)| v);
+                self.next.push_in_order(neighbors);
+            }
+        }
+    }
 }
 
 impl<'a, E> Iterator for Enumeration<'a, E>
@@ -39,25 +324,86 @@ where
     type Item = &'a VertexId;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.next.pop() {
-            if self.explored.contains(&next) {
-                return self.next();
+        while let Some((next, depth)) = self.next.pop() {
+            if !self.explored.insert(next) {
+                continue;
+            }
+            if self.max_depth.is_none_or(|max_depth| depth < max_depth) && self.filter.expands(next)
+            {
+                self.expand(next, depth);
+            }
+            if depth >= self.min_depth && self.filter.emits(next) {
+                return Some(next);
             }
-            self.explored.insert(next);
-            self.graph
-                .out_neighbors_ref(&next)
-                .for_each(|v| self.next.push(v));
-            Some(next)
-        } else {
-            None
         }
+        None
     }
 }
 
 pub type BreadthFirst<'a> = Enumeration<'a, buffer::Queue<'a>>;
 pub type DepthFirst<'a> = Enumeration<'a, buffer::Stack<'a>>;
+pub type RandomBreadthFirst<'a> = Enumeration<'a, buffer::Shuffled<'a, buffer::Queue<'a>>>;
+pub type RandomDepthFirst<'a> = Enumeration<'a, buffer::Shuffled<'a, buffer::Stack<'a>>>;
+pub type BestFirst<'a> = Enumeration<'a, buffer::PriorityBuffer<'a>>;
+pub type ZeroOneBreadthFirst<'a> = Enumeration<'a, buffer::DequeBuffer<'a>>;
+
+impl<'a, B> Enumeration<'a, buffer::Shuffled<'a, B>>
+where
+    B: buffer::Buffer<'a>,
+{
+    /// Seeds the random generator that shuffles the out neighbours of each vertex
+    ///
+    /// The same seed always gives the same enumeration, without a seed the seed 0 is used. Because all neighbours are shuffled, a [`NeighborOrder`] has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Graph, RandomDepthFirstOnTree, VertexId};
+    ///
+    /// let graph = Graph::from(4, vec![(0, 1), (0, 2), (0, 3)]).unwrap();
+    /// let enumerate =
+    ///     |seed| RandomDepthFirstOnTree::on(&graph, &VertexId(0)).seed(seed).collect::<Vec<&VertexId>>();
+    ///
+    /// assert_eq!(enumerate(7), enumerate(7));
+    /// assert_eq!(enumerate(7)[0], &VertexId(0));
+    /// assert_eq!(enumerate(7).len(), 4);
+    /// ```
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.next.reseed(seed);
+        self
+    }
+}
+
+impl<'a> Enumeration<'a, buffer::PriorityBuffer<'a>> {
+    /// Always continues with the buffered vertex that has the smallest score, which gives a greedy best first search
+    ///
+    /// The score function gets a found vertex and its depth. Vertices with the same score are enumerated in the order in which they were found. Without a score function the depth is the score, such that together with [`Enumeration::edge_lengths`] the enumeration gives the vertices in the order of their distance from the start vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{BestFirstOnTree, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(5, vec![(0, 1), (0, 2), (1, 3), (2, 4)]).unwrap();
+    /// // estimated distance to the target 4
+    /// let estimate = |v: &VertexId| [2., 3., 1., 4., 0.][v.0];
+    ///
+    /// assert_eq!(
+    ///     BestFirstOnTree::on(&graph, &VertexId(0))
+    ///         .score(move |v, _| estimate(v))
+    ///         .take_while(|v| **v != VertexId(4))
+    ///         .collect::<Vec<&VertexId>>(),
+    ///     vec![&VertexId(0), &VertexId(2)]
+    /// );
+    /// ```
+    pub fn score(mut self, score: impl Fn(&VertexId, usize) -> f64 + 'a) -> Self {
+        self.next.score_by(score);
+        self
+    }
+}
 
 #[cfg(test)]
+#[allow(clippy::useless_conversion)]
 mod tests {
     use super::*;
 
@@ -229,4 +575,350 @@ mod tests {
             vec![&VertexId(0), &VertexId(1), &VertexId(2)]
         );
     }
+
+    #[test]
+    fn only_gives_vertices_between_min_and_max_depth() {
+        let graph = Graph::from(6, vec![(0, 1), (1, 2), (2, 3), (0, 4), (4, 5)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph, &VertexId(0))
+                .min_depth(1)
+                .max_depth(2)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(1), &VertexId(4), &VertexId(2), &VertexId(5)]
+        );
+        assert_eq!(
+            DepthFirst::on(&graph, &VertexId(0))
+                .min_depth(2)
+                .max_depth(2)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(5), &VertexId(2)]
+        );
+        assert_eq!(
+            DepthFirst::on(&graph, &VertexId(0))
+                .max_depth(0)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(0)]
+        );
+    }
+
+    #[test]
+    fn does_not_explore_beyond_max_depth() {
+        let graph = Graph::from(4, vec![(0, 1), (1, 2), (2, 3)]).unwrap();
+        let mut enumeration = BreadthFirst::on(&graph, &VertexId(0)).max_depth(1);
+        assert_eq!(enumeration.by_ref().count(), 2);
+        assert_eq!(
+            enumeration.explored(),
+            HashSet::from([&VertexId(0), &VertexId(1)])
+        );
+    }
+
+    #[test]
+    fn depth_first_cuts_off_vertex_first_found_on_long_path() {
+        // the path 0 -> 1 -> 2 -> 3 -> 4 with the shortcut 0 -> 3
+        let graph = Graph::from(5, vec![(0, 3), (0, 1), (1, 2), (2, 3), (3, 4)]).unwrap();
+        assert_eq!(
+            DepthFirst::on(&graph, &VertexId(0))
+                .max_depth(3)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(0), &VertexId(1), &VertexId(2), &VertexId(3)]
+        );
+        assert_eq!(
+            BreadthFirst::on(&graph, &VertexId(0))
+                .max_depth(3)
+                .collect::<Vec<&VertexId>>(),
+            vec![
+                &VertexId(0),
+                &VertexId(3),
+                &VertexId(1),
+                &VertexId(4),
+                &VertexId(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_rejected_vertices_and_edges() {
+        let graph = Graph::from(4, vec![(0, 1), (1, 2), (0, 3), (3, 2)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph, &VertexId(0))
+                .filter_vertices(|v| v.0 % 2 == 0, RejectedVertex::NotEmitted)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(0), &VertexId(2)]
+        );
+        assert_eq!(
+            BreadthFirst::on(&graph, &VertexId(0))
+                .filter_vertices(|v| *v == VertexId(0), RejectedVertex::NotExpanded)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(0), &VertexId(1), &VertexId(3)]
+        );
+        assert_eq!(
+            DepthFirst::on(&graph, &VertexId(0))
+                .filter_edges(|edge| *edge != Edge(VertexId(1), VertexId(2)))
+                .filter_vertices(|v| *v != VertexId(3), RejectedVertex::NotExpanded)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(0), &VertexId(3), &VertexId(1)]
+        );
+        assert_eq!(
+            DepthFirst::on(&graph, &VertexId(0))
+                .filter_vertices(|_| false, RejectedVertex::NotEmitted)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn starts_at_several_vertices() {
+        let graph = Graph::from(5, vec![(0, 1), (1, 2), (3, 4), (3, 1)]).unwrap();
+        let starts = [VertexId(0), VertexId(3), VertexId(5)];
+        assert_eq!(
+            BreadthFirst::on_all(&graph, &starts).collect::<Vec<&VertexId>>(),
+            vec![
+                &VertexId(0),
+                &VertexId(3),
+                &VertexId(1),
+                &VertexId(4),
+                &VertexId(2)
+            ]
+        );
+        assert_eq!(
+            DepthFirst::on_all(&graph, &starts)
+                .min_depth(1)
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(1), &VertexId(2), &VertexId(4)]
+        );
+    }
+
+    #[test]
+    fn visits_neighbors_in_given_order() {
+        let graph = Graph::from(4, vec![(0, 2), (0, 3), (0, 1), (1, 3)]).unwrap();
+        let enumerate = |enumeration: DepthFirst| enumeration.map(|v| v.0).collect::<Vec<usize>>();
+        assert_eq!(
+            enumerate(DepthFirst::on(&graph, &VertexId(0))),
+            vec![0, 1, 3, 2]
+        );
+        assert_eq!(
+            enumerate(
+                DepthFirst::on(&graph, &VertexId(0)).neighbor_order(NeighborOrder::Insertion)
+            ),
+            vec![0, 2, 3, 1]
+        );
+        assert_eq!(
+            enumerate(DepthFirst::on(&graph, &VertexId(0)).neighbor_order(NeighborOrder::Sorted)),
+            vec![0, 1, 3, 2]
+        );
+        assert_eq!(
+            enumerate(DepthFirst::on(&graph, &VertexId(0)).neighbor_order(NeighborOrder::Reverse)),
+            vec![0, 1, 3, 2]
+        );
+        assert_eq!(
+            enumerate(
+                DepthFirst::on(&graph, &VertexId(0))
+                    .neighbor_order(NeighborOrder::by(|a, b| b.cmp(a)))
+            ),
+            vec![0, 3, 2, 1]
+        );
+        assert_eq!(
+            BreadthFirst::on(&graph, &VertexId(0))
+                .neighbor_order(NeighborOrder::Sorted)
+                .map(|v| v.0)
+                .collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn random_enumeration_finds_all_vertices_in_reproducible_order() {
+        let graph = Graph::from(
+            8,
+            (1..8).map(|to| (0, to)).chain([(3, 7), (7, 3)]).collect(),
+        )
+        .unwrap();
+        let depth_first = |seed| {
+            RandomDepthFirst::on(&graph, &VertexId(0))
+                .seed(seed)
+                .map(|v| v.0)
+                .collect::<Vec<usize>>()
+        };
+        let breadth_first = |seed| {
+            RandomBreadthFirst::on(&graph, &VertexId(0))
+                .seed(seed)
+                .map(|v| v.0)
+                .collect::<Vec<usize>>()
+        };
+        let enumerations: [&dyn Fn(u64) -> Vec<usize>; 2] = [&depth_first, &breadth_first];
+        for enumerate in enumerations {
+            assert_eq!(enumerate(1), enumerate(1));
+            assert_eq!(enumerate(1)[0], 0);
+            let mut vertices = enumerate(1);
+            vertices.sort();
+            assert_eq!(vertices, (0..8).collect::<Vec<usize>>());
+            assert!((2..20).any(|seed| enumerate(seed) != enumerate(1)));
+        }
+    }
+
+    #[test]
+    fn best_first_continues_with_smallest_score() {
+        let graph = Graph::from(6, vec![(0, 1), (0, 2), (1, 3), (2, 4), (4, 5), (1, 5)]).unwrap();
+        let scores = [0., 5., 1., 0., 2., 3.];
+        assert_eq!(
+            BestFirst::on(&graph, &VertexId(0))
+                .score(move |v, _| scores[v.0])
+                .map(|v| v.0)
+                .collect::<Vec<usize>>(),
+            vec![0, 2, 4, 5, 1, 3]
+        );
+    }
+
+    #[test]
+    fn best_first_without_score_enumerates_by_distance() {
+        // weighted 0 -3-> 1, 0 -1-> 2 -1-> 1, 1 -1-> 3
+        let graph = Graph::from(4, vec![(0, 1), (0, 2), (2, 1), (1, 3)]).unwrap();
+        let lengths = [3, 1, 1, 1];
+        let mut enumeration = BestFirst::on(&graph, &VertexId(0))
+            .edge_lengths(move |position, _| lengths[position])
+            .min_depth(2);
+        assert_eq!(enumeration.next(), Some(&VertexId(1)));
+        assert_eq!(enumeration.next(), Some(&VertexId(3)));
+        assert_eq!(enumeration.next(), None);
+    }
+
+    #[test]
+    fn zero_one_breadth_first_enumerates_by_distance() {
+        // edges to even vertices cost nothing
+        let graph = Graph::from(
+            7,
+            vec![(0, 1), (0, 3), (1, 2), (3, 4), (2, 5), (4, 6), (6, 5)],
+        )
+        .unwrap();
+        let distances =
+            |enumeration: ZeroOneBreadthFirst| enumeration.map(|v| v.0).collect::<Vec<usize>>();
+        let length = |_: usize, edge: &Edge| usize::from(edge.1 .0 % 2 == 1);
+        assert_eq!(
+            distances(ZeroOneBreadthFirst::on(&graph, &VertexId(0)).edge_lengths(length)),
+            vec![0, 1, 2, 3, 4, 6, 5]
+        );
+        assert_eq!(
+            distances(
+                ZeroOneBreadthFirst::on(&graph, &VertexId(0))
+                    .edge_lengths(length)
+                    .min_depth(2)
+            ),
+            vec![5]
+        );
+        assert_eq!(
+            distances(ZeroOneBreadthFirst::on(&graph, &VertexId(0))),
+            BreadthFirst::on(&graph, &VertexId(0))
+                .map(|v| v.0)
+                .collect::<Vec<usize>>()
+        );
+    }
+
+    fn resumes_at_each_step<'a, E: buffer::Buffer<'a>>(
+        graph: &'a Graph,
+        configure: impl Fn(Enumeration<'a, E>) -> Enumeration<'a, E>,
+    ) {
+        let all = configure(Enumeration::on(graph, &VertexId(0))).collect::<Vec<&VertexId>>();
+        for steps in 0..=all.len() {
+            let mut enumeration = configure(Enumeration::on(graph, &VertexId(0)));
+            let mut vertices = enumeration.by_ref().take(steps).collect::<Vec<&VertexId>>();
+            vertices.extend(configure(Enumeration::resume(
+                graph,
+                enumeration.checkpoint(),
+            )));
+            assert_eq!(vertices, all);
+        }
+    }
+
+    #[test]
+    fn resumes_exactly_from_checkpoint() {
+        let graph = Graph::from(
+            7,
+            vec![
+                (0, 1),
+                (0, 2),
+                (1, 3),
+                (2, 3),
+                (3, 4),
+                (4, 1),
+                (2, 5),
+                (5, 6),
+            ],
+        )
+        .unwrap();
+        resumes_at_each_step::<buffer::Queue>(&graph, |enumeration| enumeration);
+        resumes_at_each_step::<buffer::Stack>(&graph, |enumeration| {
+            enumeration
+                .min_depth(1)
+                .max_depth(3)
+                .neighbor_order(NeighborOrder::Reverse)
+        });
+        resumes_at_each_step::<buffer::PriorityBuffer>(&graph, |enumeration| {
+            enumeration.score(|v, depth| (depth + 7 - v.0) as f64)
+        });
+        resumes_at_each_step::<buffer::DequeBuffer>(&graph, |enumeration| {
+            enumeration.edge_lengths(|position, _| position % 2)
+        });
+    }
+
+    #[test]
+    fn resumes_random_enumeration_with_its_random_generator() {
+        let graph = Graph::from(
+            9,
+            (1..5)
+                .map(|to| (0, to))
+                .chain((5..9).map(|to| (1, to)))
+                .chain([(4, 1), (8, 2)])
+                .collect(),
+        )
+        .unwrap();
+        for seed in 0..5 {
+            let all = RandomDepthFirst::on(&graph, &VertexId(0))
+                .seed(seed)
+                .collect::<Vec<&VertexId>>();
+            for steps in 0..=all.len() {
+                let mut enumeration = RandomDepthFirst::on(&graph, &VertexId(0)).seed(seed);
+                let mut vertices = enumeration.by_ref().take(steps).collect::<Vec<&VertexId>>();
+                vertices.extend(RandomDepthFirst::resume(&graph, enumeration.checkpoint()));
+                assert_eq!(vertices, all);
+            }
+            let mut enumeration = RandomBreadthFirst::on(&graph, &VertexId(0)).seed(seed);
+            let mut vertices = enumeration.by_ref().take(3).collect::<Vec<&VertexId>>();
+            vertices.extend(RandomBreadthFirst::resume(&graph, enumeration.checkpoint()));
+            assert_eq!(
+                vertices,
+                RandomBreadthFirst::on(&graph, &VertexId(0))
+                    .seed(seed)
+                    .collect::<Vec<&VertexId>>()
+            );
+        }
+    }
+
+    #[test]
+    fn checkpoint_contains_buffered_and_explored_vertices() {
+        let graph = Graph::from(4, vec![(0, 1), (0, 2), (1, 3)]).unwrap();
+        let mut enumeration = BreadthFirst::on(&graph, &VertexId(0));
+        enumeration.by_ref().take(2).count();
+        assert_eq!(
+            enumeration.checkpoint(),
+            Checkpoint {
+                buffered: vec![(VertexId(2), 1), (VertexId(3), 2)],
+                explored: vec![VertexId(0), VertexId(1)],
+                random_state: None,
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_checkpoint() {
+        let graph = Graph::from(3, vec![(0, 1), (0, 2)]).unwrap();
+        let mut enumeration = DepthFirst::on(&graph, &VertexId(0));
+        enumeration.next();
+        let json = serde_json::to_string(&enumeration.checkpoint()).unwrap();
+        assert_eq!(
+            DepthFirst::resume(&graph, serde_json::from_str(&json).unwrap())
+                .collect::<Vec<&VertexId>>(),
+            vec![&VertexId(2), &VertexId(1)]
+        );
+    }
 }
@@ -0,0 +1,70 @@
+use std::collections::VecDeque;
+
+// In contrast to the basic enumeration, a vertex is popped several times from the buffer: After
+// each popping it is restored to the same location such that it is popped again next time.
+// Withdrawing takes back the item that was pushed last.
+pub trait Buffer<T> {
+    // whether the start items are popped before all items that are pushed later, otherwise
+    // only one start item is given at a time
+    const POPS_STARTS_FIRST: bool;
+    // the first item is popped first
+    fn start(items: Vec<T>) -> Self;
+    fn push(&mut self, item: T);
+    fn pop(&mut self) -> Option<T>;
+    fn restore(&mut self, item: T);
+    fn withdraw(&mut self) -> Option<T>;
+    // all items in the order in which they would be popped
+    fn items<'b>(&'b self) -> impl Iterator<Item = &'b T>
+    where
+        T: 'b;
+}
+pub struct Queue<T>(VecDeque<T>);
+impl<T> Buffer<T> for Queue<T> {
+    const POPS_STARTS_FIRST: bool = true;
+    fn start(items: Vec<T>) -> Self {
+        Self(VecDeque::from(items))
+    }
+    fn push(&mut self, item: T) {
+        self.0.push_back(item);
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+    fn restore(&mut self, item: T) {
+        self.0.push_front(item);
+    }
+    fn withdraw(&mut self) -> Option<T> {
+        self.0.pop_back()
+    }
+    fn items<'b>(&'b self) -> impl Iterator<Item = &'b T>
+    where
+        T: 'b,
+    {
+        self.0.iter()
+    }
+}
+pub struct Stack<T>(Vec<T>);
+impl<T> Buffer<T> for Stack<T> {
+    const POPS_STARTS_FIRST: bool = false;
+    fn start(items: Vec<T>) -> Self {
+        Self(items.into_iter().rev().collect())
+    }
+    fn push(&mut self, item: T) {
+        self.0.push(item);
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+    fn restore(&mut self, item: T) {
+        self.0.push(item);
+    }
+    fn withdraw(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+    fn items<'b>(&'b self) -> impl Iterator<Item = &'b T>
+    where
+        T: 'b,
+    {
+        self.0.iter().rev()
+    }
+}
@@ -1,74 +1,122 @@
-use crate::graph::{Graph, VertexId};
-use std::{collections::HashSet, mem};
+use crate::{
+    algorithms::enumeration::{filter::RejectedVertex, order::NeighborOrder},
+    graph::{Edge, Graph, VertexId},
+};
+use std::iter;
 
-use super::tree::{self, DFSEntry};
+use super::{
+    buffer,
+    tree::{self, ClassifiedEntry, DFSEntry, Vertex},
+    visitor::{self, Control, Visitor},
+};
 
-pub struct DepthFirst<'a> {
-    graph: &'a Graph,
-    enumeration: tree::DepthFirst<'a>,
+pub struct Enumeration<'a, B> {
+    enumeration: tree::Enumeration<'a, B>,
     // TODO maybe get rid of Box dyn
     vertices: Box<dyn Iterator<Item = &'a VertexId> + 'a>,
-    explored: HashSet<VertexId>,
 }
-impl<'a> DepthFirst<'a> {
+impl<'a, B> Enumeration<'a, B>
+where
+    B: buffer::Buffer<Vertex<'a>>,
+{
     pub fn on(graph: &'a Graph) -> Self {
         let mut vertices = graph.vertices();
-        match vertices.next() {
-            None => Self {
-                graph,
-                enumeration: tree::DepthFirst::on(graph, VertexId(0)),
-                vertices: Box::new(vertices),
-                explored: HashSet::new(),
-            },
-            Some(v) => Self {
-                graph,
-                enumeration: tree::DepthFirst::on(graph, v.clone()),
-                vertices: Box::new(vertices),
-                explored: HashSet::new(),
-            },
+        let start = vertices.next().cloned().unwrap_or(VertexId(0));
+        Self {
+            enumeration: tree::Enumeration::on(graph, start),
+            vertices: Box::new(vertices),
         }
     }
 
-    fn start_new_tree(&mut self, vertex: VertexId) {
-        let old_enumeration = mem::replace(
-            &mut self.enumeration,
-            tree::DepthFirst::on(self.graph, vertex),
-        );
-        self.explored.extend(old_enumeration.explored());
+    /// Only enumerates vertices that fulfill the `predicate` completely, see [`tree::Enumeration::filter_vertices`]
+    pub fn filter_vertices(
+        self,
+        predicate: impl Fn(&VertexId) -> bool + 'a,
+        rejected: RejectedVertex,
+    ) -> Self {
+        Self {
+            enumeration: self.enumeration.filter_vertices(predicate, rejected),
+            ..self
+        }
+    }
+    /// Only enumerates edges that fulfill the `predicate`, see [`tree::Enumeration::filter_edges`]
+    pub fn filter_edges(self, predicate: impl Fn(&Edge) -> bool + 'a) -> Self {
+        Self {
+            enumeration: self.enumeration.filter_edges(predicate),
+            ..self
+        }
+    }
+
+    /// Enumerates the out edges of each vertex in the given order, see [`tree::Enumeration::neighbor_order`]
+    pub fn neighbor_order(self, order: NeighborOrder<'a>) -> Self {
+        Self {
+            enumeration: self.enumeration.neighbor_order(order),
+            ..self
+        }
+    }
+
+    /// Gives the entries of the enumeration where each edge is classified by its [`tree::EdgeKind`]
+    ///
+    /// Edges to vertices of previous trees are cross edges.
+    pub fn classified(mut self) -> impl Iterator<Item = ClassifiedEntry> + 'a
+    where
+        B: 'a,
+    {
+        iter::from_fn(move || self.next_classified())
+    }
+
+    /// Calls the visitor for each entry of the enumeration
+    ///
+    /// Works like [`tree::Enumeration::visit`], pruning and stopping apply to the complete graph.
+    pub fn visit(&mut self, visitor: &mut impl Visitor) -> Control {
+        visitor::visit(self, visitor)
     }
-    fn make_sure_that_entry_was_not_already_given(&mut self, entry: DFSEntry) -> Option<DFSEntry> {
-        if let DFSEntry::BeginVertex(v) = entry.clone() {
-            if self.explored.contains(&v) {
-                self.enumeration.drop_current_vertex();
-                return None;
+
+    // All trees share one enumeration, therefore vertices of previous trees are not
+    // enumerated again
+    fn next_classified(&mut self) -> Option<ClassifiedEntry> {
+        loop {
+            if let Some(entry) = self.enumeration.next_classified() {
+                return Some(entry);
             }
+            self.enumeration.restart_at(self.vertices.next()?.clone());
         }
-        Some(entry)
     }
 }
 
-impl<'a> Iterator for DepthFirst<'a> {
+impl<'a, B> visitor::Steer for Enumeration<'a, B>
+where
+    B: buffer::Buffer<Vertex<'a>>,
+{
+    fn next_classified(&mut self) -> Option<ClassifiedEntry> {
+        Enumeration::next_classified(self)
+    }
+    fn prune_current_vertex(&mut self) {
+        self.enumeration.prune_current_vertex()
+    }
+    fn prune_current_tree_edge(&mut self) {
+        self.enumeration.prune_current_tree_edge()
+    }
+}
+
+impl<'a, B> Iterator for Enumeration<'a, B>
+where
+    B: buffer::Buffer<Vertex<'a>>,
+{
     type Item = DFSEntry;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.enumeration.next() {
-            Some(entry) => self
-                .make_sure_that_entry_was_not_already_given(entry)
-                .or_else(|| self.next()),
-            None => match self.vertices.next() {
-                Some(v) => {
-                    self.start_new_tree(v.clone());
-                    self.next()
-                }
-                None => None,
-            },
-        }
+        self.next_classified().map(DFSEntry::from)
     }
 }
 
+pub type BreadthFirst<'a> = Enumeration<'a, buffer::Queue<Vertex<'a>>>;
+pub type DepthFirst<'a> = Enumeration<'a, buffer::Stack<Vertex<'a>>>;
+
 #[cfg(test)]
+#[allow(clippy::useless_conversion)]
 mod tests {
-    use crate::graph::Edge;
+    use crate::algorithms::enumeration::detailed::tree::EdgeKind;
 
     use super::*;
 
@@ -238,4 +286,94 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn breadth_first_iterates_over_each_component_breadth_first() {
+        let graph = Graph::from(5, vec![(0, 1), (0, 2), (1, 2), (3, 4)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph).collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(2))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(2))),
+                DFSEntry::EndVertex(VertexId(0)),
+                DFSEntry::BeginVertex(VertexId(1)),
+                DFSEntry::BeginEdge(Edge(VertexId(1), VertexId(2))),
+                DFSEntry::EndEdge(Edge(VertexId(1), VertexId(2))),
+                DFSEntry::EndVertex(VertexId(1)),
+                DFSEntry::BeginVertex(VertexId(2)),
+                DFSEntry::EndVertex(VertexId(2)),
+                DFSEntry::BeginVertex(VertexId(3)),
+                DFSEntry::BeginEdge(Edge(VertexId(3), VertexId(4))),
+                DFSEntry::EndEdge(Edge(VertexId(3), VertexId(4))),
+                DFSEntry::EndVertex(VertexId(3)),
+                DFSEntry::BeginVertex(VertexId(4)),
+                DFSEntry::EndVertex(VertexId(4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn breadth_first_finds_rest_of_tree_when_bfs_does_not_start_at_its_root() {
+        let graph = Graph::from(4, vec![(3, 1), (2, 3), (2, 0)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph).collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::EndVertex(VertexId(0)),
+                DFSEntry::BeginVertex(VertexId(1)),
+                DFSEntry::EndVertex(VertexId(1)),
+                DFSEntry::BeginVertex(VertexId(2)),
+                DFSEntry::BeginEdge(Edge(VertexId(2), VertexId(3))),
+                DFSEntry::EndEdge(Edge(VertexId(2), VertexId(3))),
+                DFSEntry::BeginEdge(Edge(VertexId(2), VertexId(0))),
+                DFSEntry::EndEdge(Edge(VertexId(2), VertexId(0))),
+                DFSEntry::EndVertex(VertexId(2)),
+                DFSEntry::BeginVertex(VertexId(3)),
+                DFSEntry::BeginEdge(Edge(VertexId(3), VertexId(1))),
+                DFSEntry::EndEdge(Edge(VertexId(3), VertexId(1))),
+                DFSEntry::EndVertex(VertexId(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn edges_to_previous_trees_are_cross_edges() {
+        let graph = Graph::from(3, vec![(0, 1), (2, 1), (2, 0)]).unwrap();
+        assert_eq!(
+            DepthFirst::on(&graph)
+                .classified()
+                .filter_map(|entry| match entry {
+                    ClassifiedEntry::BeginEdge(Edge(from, to), kind) => Some((from.0, to.0, kind)),
+                    _ => None,
+                })
+                .collect::<Vec<(usize, usize, EdgeKind)>>(),
+            vec![
+                (0, 1, EdgeKind::Tree),
+                (2, 1, EdgeKind::Cross),
+                (2, 0, EdgeKind::Cross),
+            ]
+        );
+    }
+
+    #[test]
+    fn filters_in_all_trees() {
+        let graph = Graph::from(3, vec![(0, 1), (2, 1)]).unwrap();
+        assert_eq!(
+            DepthFirst::on(&graph)
+                .filter_vertices(|v| *v != VertexId(1), RejectedVertex::NotEmitted)
+                .filter_edges(|edge| *edge != Edge(VertexId(2), VertexId(1)))
+                .collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndVertex(VertexId(0)),
+                DFSEntry::BeginVertex(VertexId(2)),
+                DFSEntry::EndVertex(VertexId(2)),
+            ]
+        );
+    }
 }
@@ -1,2 +1,4 @@
+mod buffer;
 pub mod graph;
 pub mod tree;
+pub mod visitor;
@@ -1,35 +1,61 @@
 use std::{
-    collections::{HashSet, VecDeque},
-    fmt,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt, iter,
 };
 
-use crate::graph::{Edge, Graph, VertexId};
+use super::{
+    buffer,
+    visitor::{self, Control, Visitor},
+};
+use crate::{
+    algorithms::enumeration::{
+        filter::{Filter, RejectedVertex},
+        order::NeighborOrder,
+    },
+    graph::{Edge, Graph, VertexId},
+};
+
+type Neighbours<'a> = Box<dyn Iterator<Item = VertexId> + 'a>;
 
-struct Vertex<'a> {
+/// A vertex in the buffer of a detailed enumeration together with the state of its out edges
+pub struct Vertex<'a> {
     id: VertexId,
-    current_neighbour: Option<VertexId>,
-    neighbours: Box<dyn Iterator<Item = VertexId> + 'a>,
-    dropped: bool,
+    current_edge: Option<(VertexId, EdgeKind)>,
+    // the neighbours are only looked up when the vertex is expanded, without the ones that
+    // were already taken before the enumeration was resumed
+    neighbours: Option<Neighbours<'a>>,
+    taken: usize,
+    pruned: bool,
 }
 impl<'a> Vertex<'a> {
-    fn from(vertex: VertexId, graph: &'a Graph) -> Self {
+    fn from(vertex: VertexId) -> Self {
         Self {
-            id: vertex.clone(),
-            current_neighbour: None,
-            neighbours: Box::new(graph.out_neighbors(vertex)),
-            dropped: false,
+            id: vertex,
+            current_edge: None,
+            neighbours: None,
+            taken: 0,
+            pruned: false,
         }
     }
-    fn next_neighbour(mut self) -> Self {
+    fn next_neighbour(&mut self) -> Option<VertexId> {
+        self.current_edge = None;
+        let neighbour = self.neighbours.as_mut()?.next();
+        self.taken += usize::from(neighbour.is_some());
+        neighbour
+    }
+    fn prune(self) -> Self {
         Self {
-            current_neighbour: self.neighbours.next(),
+            neighbours: Some(Box::new(iter::empty())),
+            pruned: true,
             ..self
         }
     }
-    fn drop(self) -> Self {
-        Self {
-            dropped: true,
-            ..self
+    fn state(&self) -> BufferedVertex {
+        BufferedVertex {
+            id: self.id.clone(),
+            current_edge: self.current_edge.clone(),
+            taken: self.taken,
+            pruned: self.pruned,
         }
     }
 }
@@ -37,93 +63,432 @@ impl<'a> fmt::Debug for Vertex<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Vertex")
             .field("id", &self.id)
-            .field("current_neighbour", &self.current_neighbour)
-            .field("dropped", &self.dropped)
+            .field("current_edge", &self.current_edge)
             .finish()
     }
 }
 
-// Generalization to enumeration that covers both depth first and breadth first
-// requires two changes:
-// - When vertex is not ended yet, it has to be restored to buffer,
-//   which can be a stack or a queue, such that it goes back to the same location
-//   it was before it was popped.
-// - Handle double edges (having same source and sink). Currently the sink vertex
-//   is pushed twice to the buffer, which is fine for edges but also results in
-//   returning EndVertex twice for the sink.
-pub struct DepthFirst<'a> {
+/// State of a vertex in the buffer of a detailed enumeration, see [`Checkpoint`]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BufferedVertex {
+    pub id: VertexId,
+    /// Out edge that was begun last but is not ended yet, given by its target vertex
+    pub current_edge: Option<(VertexId, EdgeKind)>,
+    /// Number of out neighbours that were already enumerated
+    pub taken: usize,
+    /// The remaining out edges of the vertex are not enumerated
+    pub pruned: bool,
+}
+
+/// Complete state of a detailed enumeration on a tree, from which the enumeration can be resumed
+///
+/// The checkpoint only consists of vertices and edges, with the `serde` feature it can be serialized. Filters and a neighbour order are not part of the checkpoint, they have to be given again to the resumed enumeration.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint {
+    /// Vertices in the buffer in the order in which they are popped
+    pub buffer: Vec<BufferedVertex>,
+    /// Explored vertices in the order in which they were begun
+    pub explored: Vec<VertexId>,
+    /// Vertices that were found, sorted by id
+    pub discovered: Vec<VertexId>,
+    /// Vertices that are begun but not yet ended, sorted by id
+    pub active: Vec<VertexId>,
+    /// Entries that are already determined but not yet given, in the order in which they are given
+    pub output_queue: Vec<ClassifiedEntry>,
+    /// Start vertices of a depth first enumeration that are not yet begun, in the order in which they are begun
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pending_starts: Vec<VertexId>,
+}
+
+// The enumeration covers both depth first and breadth first, depending on the buffer:
+// - A vertex that is not ended yet is restored to the buffer such that it goes back to
+//   the same location it was before it was popped.
+// - A vertex is only pushed to the buffer once, even if it is found via several edges
+//   (e.g. double edges having the same source and sink), such that it is only begun
+//   and ended once.
+pub struct Enumeration<'a, B> {
     graph: &'a Graph,
-    stack: Vec<Vertex<'a>>,
-    explored: HashSet<VertexId>,
-    output_queue: VecDeque<DFSEntry>,
+    buffer: B,
+    // explored vertices together with the position in which they were begun
+    explored: HashMap<VertexId, usize>,
+    discovered: HashSet<VertexId>,
+    // vertices that are begun but not yet ended
+    active: HashSet<VertexId>,
+    output_queue: VecDeque<ClassifiedEntry>,
+    // start vertices that are only begun when the buffer is empty, such that they can still be
+    // found as part of a previous tree
+    pending_starts: VecDeque<VertexId>,
+    filter: Filter<'a>,
+    order: Option<NeighborOrder<'a>>,
 }
-impl<'a> DepthFirst<'a> {
+impl<'a, B> Enumeration<'a, B>
+where
+    B: buffer::Buffer<Vertex<'a>>,
+{
     pub fn on(graph: &'a Graph, start: VertexId) -> Self {
-        if graph.contains(&start) {
-            Self {
-                graph,
-                stack: vec![Vertex::from(start, graph)],
-                explored: HashSet::new(),
-                output_queue: VecDeque::new(),
-            }
-        } else {
-            Self {
-                graph,
-                stack: vec![],
-                explored: HashSet::new(),
-                output_queue: VecDeque::new(),
-            }
+        Self::on_all(graph, [start])
+    }
+    /// Starts the enumeration at several vertices at once
+    ///
+    /// Each start vertex is the root of its own tree, unless it is already found in the tree of a previous start vertex. A breadth first enumeration begins all start vertices before their neighbours, therefore edges between them are cross edges. A depth first enumeration completely enumerates the tree of one start vertex before it continues with the next one, a later start vertex that is found on the way is part of that tree and is reached by a tree edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{DFSEntry, DetailedDepthFirstOnTree, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(3, vec![(0, 1)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     DetailedDepthFirstOnTree::on_all(&graph, [VertexId(2), VertexId(0)])
+    ///         .filter(|entry| matches!(entry, DFSEntry::BeginVertex(_)))
+    ///         .collect::<Vec<DFSEntry>>(),
+    ///     vec![
+    ///         DFSEntry::BeginVertex(VertexId(2)),
+    ///         DFSEntry::BeginVertex(VertexId(0)),
+    ///         DFSEntry::BeginVertex(VertexId(1)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn on_all(graph: &'a Graph, starts: impl IntoIterator<Item = VertexId>) -> Self {
+        let mut unique = HashSet::new();
+        let mut starts = starts
+            .into_iter()
+            .filter(|start| graph.contains(start) && unique.insert(start.clone()))
+            .collect::<VecDeque<VertexId>>();
+        // pending start vertices are not discovered yet, such that they can be found via a tree edge
+        let begun = match B::POPS_STARTS_FIRST {
+            true => starts.drain(..).collect::<Vec<VertexId>>(),
+            false => starts.pop_front().into_iter().collect(),
+        };
+        Self {
+            graph,
+            discovered: begun.iter().cloned().collect(),
+            buffer: B::start(begun.into_iter().map(Vertex::from).collect()),
+            explored: HashMap::new(),
+            active: HashSet::new(),
+            output_queue: VecDeque::new(),
+            pending_starts: starts,
+            filter: Filter::new(),
+            order: None,
+        }
+    }
+    /// Only enumerates vertices that fulfill the `predicate` completely
+    ///
+    /// What happens with the other vertices is defined by `rejected`: A vertex that is not emitted does not get a begin and an end entry, but its edges are still enumerated. A vertex that is not expanded is begun and ended directly without enumerating its out edges.
+    pub fn filter_vertices(
+        self,
+        predicate: impl Fn(&VertexId) -> bool + 'a,
+        rejected: RejectedVertex,
+    ) -> Self {
+        Self {
+            filter: self.filter.with_vertices(predicate, rejected),
+            ..self
+        }
+    }
+    /// Only enumerates edges that fulfill the `predicate`
+    ///
+    /// The enumeration behaves as if the other edges were not part of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{DFSEntry, DetailedDepthFirstOnTree, Edge, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(2, vec![(0, 1), (0, 0)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     DetailedDepthFirstOnTree::on(&graph, VertexId(0))
+    ///         .filter_edges(|Edge(from, to)| from != to)
+    ///         .filter(|entry| matches!(entry, DFSEntry::BeginEdge(_)))
+    ///         .collect::<Vec<DFSEntry>>(),
+    ///     vec![DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1)))]
+    /// );
+    /// ```
+    pub fn filter_edges(self, predicate: impl Fn(&Edge) -> bool + 'a) -> Self {
+        Self {
+            filter: self.filter.with_edges(predicate),
+            ..self
+        }
+    }
+    /// Enumerates the out edges of each vertex in the given order, see [`NeighborOrder`]
+    ///
+    /// Without a given order the out edges are enumerated in insertion order.
+    pub fn neighbor_order(self, order: NeighborOrder<'a>) -> Self {
+        Self {
+            order: Some(order),
+            ..self
         }
     }
     pub fn explored(self) -> HashSet<VertexId> {
-        self.explored
+        self.explored.into_keys().collect()
     }
-    pub fn drop_current_vertex(&mut self) {
-        if let Some(v) = self.stack.pop() {
-            self.stack.push(v.drop());
+
+    /// Gives the current state of the enumeration, see [`Checkpoint`]
+    pub fn checkpoint(&self) -> Checkpoint {
+        let mut explored = self.explored.iter().collect::<Vec<(&VertexId, &usize)>>();
+        explored.sort_by_key(|(_, position)| **position);
+        let mut discovered = self.discovered.iter().cloned().collect::<Vec<VertexId>>();
+        discovered.sort();
+        let mut active = self.active.iter().cloned().collect::<Vec<VertexId>>();
+        active.sort();
+        Checkpoint {
+            buffer: self.buffer.items().map(Vertex::state).collect(),
+            explored: explored.into_iter().map(|(v, _)| v.clone()).collect(),
+            discovered,
+            active,
+            output_queue: self.output_queue.iter().rev().cloned().collect(),
+            pending_starts: self.pending_starts.iter().cloned().collect(),
+        }
+    }
+    /// Continues an enumeration exactly where the enumeration stopped whose [`Checkpoint`] is given
+    ///
+    /// The checkpoint has to be taken on the same graph. Filters and a neighbour order of the stopped enumeration have to be given again to the resumed enumeration, such that it continues in the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{DFSEntry, DetailedDepthFirstOnTree, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(4, vec![(0, 1), (1, 2), (0, 3)]).unwrap();
+    /// let mut enumeration = DetailedDepthFirstOnTree::on(&graph, VertexId(0));
+    /// let mut entries = enumeration.by_ref().take(4).collect::<Vec<DFSEntry>>();
+    /// let checkpoint = enumeration.checkpoint();
+    ///
+    /// entries.extend(DetailedDepthFirstOnTree::resume(&graph, checkpoint));
+    ///
+    /// assert_eq!(
+    ///     entries,
+    ///     DetailedDepthFirstOnTree::on(&graph, VertexId(0)).collect::<Vec<DFSEntry>>()
+    /// );
+    /// ```
+    pub fn resume(graph: &'a Graph, checkpoint: Checkpoint) -> Self {
+        let enumeration = Self::on_all(graph, []);
+        let buffer = checkpoint
+            .buffer
+            .into_iter()
+            .map(Self::restore_vertex)
+            .collect();
+        Self {
+            buffer: B::start(buffer),
+            explored: checkpoint
+                .explored
+                .into_iter()
+                .enumerate()
+                .map(|(position, v)| (v, position))
+                .collect(),
+            discovered: checkpoint.discovered.into_iter().collect(),
+            active: checkpoint.active.into_iter().collect(),
+            output_queue: checkpoint.output_queue.into_iter().rev().collect(),
+            pending_starts: checkpoint.pending_starts.into_iter().collect(),
+            ..enumeration
         }
     }
 
-    fn begin_vertex(&mut self, vertex: VertexId) -> Option<DFSEntry> {
-        match self.explored.contains(&vertex) {
-            true => None,
-            false => {
-                self.explored.insert(vertex.clone());
-                Some(DFSEntry::BeginVertex(vertex))
-            }
+    /// Gives the entries of the enumeration where each edge is classified by its [`EdgeKind`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{ClassifiedEntry, DetailedDepthFirstOnTree, EdgeKind, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(3, vec![(0, 1), (1, 2), (2, 0)]).unwrap();
+    ///
+    /// assert!(DetailedDepthFirstOnTree::on(&graph, VertexId(0))
+    ///     .classified()
+    ///     .any(|entry| matches!(entry, ClassifiedEntry::BeginEdge(_, EdgeKind::Back))));
+    /// ```
+    pub fn classified(mut self) -> impl Iterator<Item = ClassifiedEntry> + 'a
+    where
+        B: 'a,
+    {
+        iter::from_fn(move || self.next_classified())
+    }
+
+    /// Calls the visitor for each entry of the enumeration
+    ///
+    /// The visitor steers the enumeration: It can prune a vertex or a tree edge or stop the enumeration, see [`Control`]. The enumeration can be continued after it was stopped by calling this function again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Control, DetailedDepthFirstOnTree, Graph, VertexId, Visitor};
+    ///
+    /// struct Collect(Vec<VertexId>);
+    /// impl Visitor for Collect {
+    ///     fn on_begin_vertex(&mut self, vertex: &VertexId) -> Control {
+    ///         self.0.push(vertex.clone());
+    ///         match vertex {
+    ///             VertexId(1) => Control::Prune,
+    ///             _ => Control::Continue,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let graph = Graph::from(4, vec![(0, 1), (1, 2), (0, 3)]).unwrap();
+    /// let mut visitor = Collect(Vec::new());
+    /// DetailedDepthFirstOnTree::on(&graph, VertexId(0)).visit(&mut visitor);
+    ///
+    /// assert_eq!(visitor.0, vec![VertexId(0), VertexId(1), VertexId(3)]);
+    /// ```
+    pub fn visit(&mut self, visitor: &mut impl Visitor) -> Control {
+        visitor::visit(self, visitor)
+    }
+
+    // Starts a new tree in the same enumeration such that vertices that are already
+    // explored are not enumerated again, edges to them are enumerated but not followed
+    pub(super) fn restart_at(&mut self, start: VertexId) {
+        if self.graph.contains(&start) && self.discovered.insert(start.clone()) {
+            self.buffer.push(Vertex::from(start));
+        }
+    }
+
+    fn begin_vertex(&mut self, vertex: Vertex<'a>) -> (Vertex<'a>, Option<ClassifiedEntry>) {
+        let id = vertex.id.clone();
+        self.explored.insert(id.clone(), self.explored.len());
+        self.active.insert(id.clone());
+        let vertex = match self.filter.expands(&id) {
+            true => vertex,
+            false => vertex.prune(),
+        };
+        let entry = self
+            .filter
+            .emits(&id)
+            .then_some(ClassifiedEntry::BeginVertex(id));
+        (vertex, entry)
+    }
+    fn end_previous_edge(&self, vertex: &Vertex) -> Option<ClassifiedEntry> {
+        vertex.current_edge.clone().map(|(neighbour, kind)| {
+            ClassifiedEntry::EndEdge(Edge(vertex.id.clone(), neighbour), kind)
+        })
+    }
+    fn end_vertex(&mut self, vertex: VertexId) -> Option<ClassifiedEntry> {
+        self.active.remove(&vertex);
+        self.filter
+            .emits(&vertex)
+            .then_some(ClassifiedEntry::EndVertex(vertex))
+    }
+    fn restore_vertex(vertex: BufferedVertex) -> Vertex<'a> {
+        let restored = Vertex {
+            id: vertex.id,
+            current_edge: vertex.current_edge,
+            neighbours: None,
+            taken: vertex.taken,
+            pruned: false,
+        };
+        match vertex.pruned {
+            true => restored.prune(),
+            false => restored,
         }
     }
-    fn end_previous_edge(&self, vertex: &Vertex) -> Option<DFSEntry> {
-        vertex
-            .current_neighbour
-            .clone()
-            .and_then(|neighbour| Some(DFSEntry::EndEdge(Edge(vertex.id.clone(), neighbour))))
-    }
-    fn end_vertex(&self, vertex: &Vertex) -> Option<DFSEntry> {
-        if let None = vertex.current_neighbour {
-            if !vertex.dropped {
-                let id = vertex.id.clone();
-                return Some(DFSEntry::EndVertex(id));
+    fn neighbours_of(&self, vertex: &VertexId) -> Neighbours<'a> {
+        let neighbours = self.graph.out_neighbors(vertex.clone());
+        match &self.order {
+            None => Box::new(neighbours),
+            Some(order) => {
+                let mut neighbours = neighbours.collect::<Vec<VertexId>>();
+                order.arrange(&mut neighbours);
+                Box::new(neighbours.into_iter())
             }
         }
-        None
-    }
-    // TODO if Vertex<'a> is cloneable: give clone to this function instead of current 2 arguments
-    fn begin_next_edge(
-        &mut self,
-        vertex: VertexId,
-        current_neighbour: Option<VertexId>,
-    ) -> Option<DFSEntry> {
-        current_neighbour.clone().and_then(|neighbour| {
-            if !self.explored.contains(&neighbour) {
-                self.stack.push(Vertex::from(neighbour.clone(), self.graph));
+    }
+    fn next_followed_neighbour(&self, vertex: &mut Vertex<'a>) -> Option<VertexId> {
+        if vertex.neighbours.is_none() {
+            vertex.neighbours = Some(Box::new(self.neighbours_of(&vertex.id).skip(vertex.taken)));
+        }
+        let id = vertex.id.clone();
+        iter::from_fn(|| vertex.next_neighbour())
+            .find(|neighbour| self.filter.follows(&Edge(id.clone(), neighbour.clone())))
+    }
+    fn classify(&self, from: &VertexId, to: &VertexId) -> EdgeKind {
+        if !self.discovered.contains(to) {
+            return EdgeKind::Tree;
+        }
+        if self.active.contains(to) {
+            return EdgeKind::Back;
+        }
+        match (self.explored.get(from), self.explored.get(to)) {
+            (Some(from), Some(to)) if from < to => EdgeKind::Forward,
+            _ => EdgeKind::Cross,
+        }
+    }
+    // The vertex has to be restored before its neighbour is pushed to the buffer
+    fn begin_next_edge(&mut self, mut vertex: Vertex<'a>, neighbour: VertexId) -> ClassifiedEntry {
+        let kind = self.classify(&vertex.id, &neighbour);
+        let edge = Edge(vertex.id.clone(), neighbour.clone());
+        vertex.current_edge = Some((neighbour.clone(), kind.clone()));
+        self.buffer.restore(vertex);
+        if kind == EdgeKind::Tree {
+            self.discovered.insert(neighbour.clone());
+            self.buffer.push(Vertex::from(neighbour));
+        }
+        ClassifiedEntry::BeginEdge(edge, kind)
+    }
+
+    pub(super) fn next_classified(&mut self) -> Option<ClassifiedEntry> {
+        loop {
+            if !self.output_queue.is_empty() {
+                return self.output_queue.pop_back();
             }
-            Some(DFSEntry::BeginEdge(Edge(vertex, neighbour)))
-        })
+            let Some(mut vertex) = self.buffer.pop() else {
+                let start = self.pending_starts.pop_front()?;
+                self.restart_at(start);
+                continue;
+            };
+            if !self.explored.contains_key(&vertex.id) {
+                let (begun_vertex, entry) = self.begin_vertex(vertex);
+                vertex = begun_vertex;
+                if entry.is_some() {
+                    self.buffer.restore(vertex);
+                    // directly return here to make sure that the returned started vertex
+                    // can be pruned afterwards
+                    return entry;
+                }
+            }
+
+            if let Some(entry) = self.end_previous_edge(&vertex) {
+                self.output_queue.push_front(entry);
+            }
+
+            let entry = match self.next_followed_neighbour(&mut vertex) {
+                Some(neighbour) => Some(self.begin_next_edge(vertex, neighbour)),
+                None => self.end_vertex(vertex.id),
+            };
+            if let Some(entry) = entry {
+                self.output_queue.push_front(entry);
+            }
+        }
     }
 }
 
+impl<'a, B> visitor::Steer for Enumeration<'a, B>
+where
+    B: buffer::Buffer<Vertex<'a>>,
+{
+    fn next_classified(&mut self) -> Option<ClassifiedEntry> {
+        Enumeration::next_classified(self)
+    }
+    // the begun vertex was restored to the buffer and is popped next
+    fn prune_current_vertex(&mut self) {
+        if let Some(vertex) = self.buffer.pop() {
+            self.buffer.restore(vertex.prune());
+        }
+    }
+    // the target of the tree edge was the last vertex pushed to the buffer
+    fn prune_current_tree_edge(&mut self) {
+        if let Some(vertex) = self.buffer.withdraw() {
+            self.discovered.remove(&vertex.id);
+        }
+    }
+}
+
+pub type BreadthFirst<'a> = Enumeration<'a, buffer::Queue<Vertex<'a>>>;
+pub type DepthFirst<'a> = Enumeration<'a, buffer::Stack<Vertex<'a>>>;
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DFSEntry {
     BeginVertex(VertexId),
     BeginEdge(Edge),
@@ -131,52 +496,55 @@ pub enum DFSEntry {
     EndEdge(Edge),
 }
 
-impl<'a> Iterator for DepthFirst<'a> {
-    type Item = DFSEntry;
+/// Kind of an edge relative to the trees of a depth first enumeration
+///
+/// The kind depends on the state of the target vertex when the edge is begun. In a breadth first enumeration there are no forward edges: A target vertex that was already found is either begun later or already ended, so that edge is a cross edge (with the exception of loops, which are back edges).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeKind {
+    /// The target vertex is found for the first time, the edge is part of the tree
+    Tree,
+    /// The target vertex is begun but not yet ended, the edge leads to an ancestor and closes a cycle
+    Back,
+    /// The target vertex is already ended and was begun after the source vertex, it is a descendant in the tree
+    Forward,
+    /// All other edges, e.g. to another branch of the tree or to a previous tree
+    Cross,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if !self.output_queue.is_empty() {
-            return self.output_queue.pop_back();
+/// Entry of a detailed enumeration where each edge is classified by its [`EdgeKind`]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClassifiedEntry {
+    BeginVertex(VertexId),
+    BeginEdge(Edge, EdgeKind),
+    EndVertex(VertexId),
+    EndEdge(Edge, EdgeKind),
+}
+impl From<ClassifiedEntry> for DFSEntry {
+    fn from(entry: ClassifiedEntry) -> Self {
+        match entry {
+            ClassifiedEntry::BeginVertex(vertex) => DFSEntry::BeginVertex(vertex),
+            ClassifiedEntry::BeginEdge(edge, _) => DFSEntry::BeginEdge(edge),
+            ClassifiedEntry::EndVertex(vertex) => DFSEntry::EndVertex(vertex),
+            ClassifiedEntry::EndEdge(edge, _) => DFSEntry::EndEdge(edge),
         }
-        match self.stack.pop() {
-            None => None,
-            Some(vertex) => {
-                if let Some(entry) = self.begin_vertex(vertex.id.clone()) {
-                    self.stack.push(vertex);
-                    // directly return here to make sure that the returned started vertex
-                    // can be dropped by calling drop_current_vertex afterwards
-                    return Some(entry);
-                }
+    }
+}
 
-                self.end_previous_edge(&vertex)
-                    .and_then(|x| Some(self.output_queue.push_front(x)));
-
-                let updated_vertex = vertex.next_neighbour();
-                let updated_vertex_id = updated_vertex.id.clone();
-                let updated_current_neighbour = updated_vertex.current_neighbour.clone();
-                let updated_dropped = updated_vertex.dropped;
-
-                self.end_vertex(&updated_vertex)
-                    .and_then(|x| Some(self.output_queue.push_front(x)))
-                    .or_else(|| {
-                        if !updated_vertex.dropped {
-                            self.stack.push(updated_vertex);
-                        }
-                        None
-                    });
-
-                if !updated_dropped {
-                    self.begin_next_edge(updated_vertex_id, updated_current_neighbour)
-                        .and_then(|x| Some(self.output_queue.push_front(x)));
-                }
+impl<'a, B> Iterator for Enumeration<'a, B>
+where
+    B: buffer::Buffer<Vertex<'a>>,
+{
+    type Item = DFSEntry;
 
-                self.next()
-            }
-        }
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_classified().map(DFSEntry::from)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_conversion)]
 mod tests {
     use super::*;
 
@@ -345,4 +713,391 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn breadth_first_enumerates_vertices_breadth_first() {
+        let graph = Graph::from(6, vec![(0, 1), (0, 2), (4, 5), (1, 3), (1, 4)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph, VertexId(0)).collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(2))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(2))),
+                DFSEntry::EndVertex(VertexId(0)),
+                DFSEntry::BeginVertex(VertexId(1)),
+                DFSEntry::BeginEdge(Edge(VertexId(1), VertexId(3))),
+                DFSEntry::EndEdge(Edge(VertexId(1), VertexId(3))),
+                DFSEntry::BeginEdge(Edge(VertexId(1), VertexId(4))),
+                DFSEntry::EndEdge(Edge(VertexId(1), VertexId(4))),
+                DFSEntry::EndVertex(VertexId(1)),
+                DFSEntry::BeginVertex(VertexId(2)),
+                DFSEntry::EndVertex(VertexId(2)),
+                DFSEntry::BeginVertex(VertexId(3)),
+                DFSEntry::EndVertex(VertexId(3)),
+                DFSEntry::BeginVertex(VertexId(4)),
+                DFSEntry::BeginEdge(Edge(VertexId(4), VertexId(5))),
+                DFSEntry::EndEdge(Edge(VertexId(4), VertexId(5))),
+                DFSEntry::EndVertex(VertexId(4)),
+                DFSEntry::BeginVertex(VertexId(5)),
+                DFSEntry::EndVertex(VertexId(5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn breadth_first_finds_each_vertex_only_once() {
+        let graph = Graph::from(2, vec![(0, 1), (0, 1)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph, VertexId(0)).collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndVertex(VertexId(0)),
+                DFSEntry::BeginVertex(VertexId(1)),
+                DFSEntry::EndVertex(VertexId(1)),
+            ]
+        );
+
+        let graph = Graph::from(4, vec![(0, 1), (0, 2), (1, 3), (2, 3)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph, VertexId(0))
+                .filter(|entry| matches!(entry, DFSEntry::BeginVertex(_)))
+                .collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginVertex(VertexId(1)),
+                DFSEntry::BeginVertex(VertexId(2)),
+                DFSEntry::BeginVertex(VertexId(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn breadth_first_finds_each_vertex_in_a_loop_once() {
+        let graph = Graph::from(2, vec![(0, 1), (1, 0)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph, VertexId(0)).collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndVertex(VertexId(0)),
+                DFSEntry::BeginVertex(VertexId(1)),
+                DFSEntry::BeginEdge(Edge(VertexId(1), VertexId(0))),
+                DFSEntry::EndEdge(Edge(VertexId(1), VertexId(0))),
+                DFSEntry::EndVertex(VertexId(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn classifies_edges_depth_first() {
+        let graph = Graph::from(4, vec![(0, 1), (1, 2), (2, 0), (0, 2), (0, 3), (3, 2)]).unwrap();
+        assert_eq!(
+            DepthFirst::on(&graph, VertexId(0))
+                .classified()
+                .filter_map(|entry| match entry {
+                    ClassifiedEntry::BeginEdge(Edge(from, to), kind) => Some((from.0, to.0, kind)),
+                    _ => None,
+                })
+                .collect::<Vec<(usize, usize, EdgeKind)>>(),
+            vec![
+                (0, 1, EdgeKind::Tree),
+                (1, 2, EdgeKind::Tree),
+                (2, 0, EdgeKind::Back),
+                (0, 2, EdgeKind::Forward),
+                (0, 3, EdgeKind::Tree),
+                (3, 2, EdgeKind::Cross),
+            ]
+        );
+    }
+
+    #[test]
+    fn ends_edge_with_its_kind() {
+        let graph = Graph::from(2, vec![(0, 1), (1, 1)]).unwrap();
+        assert_eq!(
+            DepthFirst::on(&graph, VertexId(0))
+                .classified()
+                .collect::<Vec<ClassifiedEntry>>(),
+            vec![
+                ClassifiedEntry::BeginVertex(VertexId(0)),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(0), VertexId(1)), EdgeKind::Tree),
+                ClassifiedEntry::BeginVertex(VertexId(1)),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(1), VertexId(1)), EdgeKind::Back),
+                ClassifiedEntry::EndEdge(Edge(VertexId(1), VertexId(1)), EdgeKind::Back),
+                ClassifiedEntry::EndVertex(VertexId(1)),
+                ClassifiedEntry::EndEdge(Edge(VertexId(0), VertexId(1)), EdgeKind::Tree),
+                ClassifiedEntry::EndVertex(VertexId(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn breadth_first_has_no_forward_edges() {
+        let graph = Graph::from(3, vec![(0, 1), (0, 2), (1, 2), (2, 0)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&graph, VertexId(0))
+                .classified()
+                .filter_map(|entry| match entry {
+                    ClassifiedEntry::BeginEdge(Edge(from, to), kind) => Some((from.0, to.0, kind)),
+                    _ => None,
+                })
+                .collect::<Vec<(usize, usize, EdgeKind)>>(),
+            vec![
+                (0, 1, EdgeKind::Tree),
+                (0, 2, EdgeKind::Tree),
+                (1, 2, EdgeKind::Cross),
+                (2, 0, EdgeKind::Cross),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_many_rejected_vertices_in_a_row() {
+        let star = Graph::from(200_001, (1..200_001).map(|to| (0, to)).collect()).unwrap();
+        assert_eq!(
+            BreadthFirst::on(&star, VertexId(0))
+                .filter_vertices(|v| v.0 == 0, RejectedVertex::NotEmitted)
+                .count(),
+            2 + 2 * 200_000
+        );
+    }
+
+    #[test]
+    fn skips_rejected_vertices_and_edges() {
+        let graph = Graph::from(3, vec![(0, 1), (1, 2), (0, 2)]).unwrap();
+        assert_eq!(
+            DepthFirst::on(&graph, VertexId(0))
+                .filter_vertices(|v| *v != VertexId(1), RejectedVertex::NotEmitted)
+                .collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::BeginEdge(Edge(VertexId(1), VertexId(2))),
+                DFSEntry::BeginVertex(VertexId(2)),
+                DFSEntry::EndVertex(VertexId(2)),
+                DFSEntry::EndEdge(Edge(VertexId(1), VertexId(2))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(2))),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(2))),
+                DFSEntry::EndVertex(VertexId(0)),
+            ]
+        );
+        assert_eq!(
+            DepthFirst::on(&graph, VertexId(0))
+                .filter_vertices(|v| *v != VertexId(1), RejectedVertex::NotExpanded)
+                .filter_edges(|edge| *edge != Edge(VertexId(0), VertexId(2)))
+                .collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::BeginVertex(VertexId(1)),
+                DFSEntry::EndVertex(VertexId(1)),
+                DFSEntry::EndEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::EndVertex(VertexId(0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn multiple_start_vertices_are_roots_unless_found_before() {
+        let graph = Graph::from(4, vec![(0, 1), (2, 3), (2, 0)]).unwrap();
+        assert_eq!(
+            BreadthFirst::on_all(&graph, [VertexId(0), VertexId(2), VertexId(0)])
+                .classified()
+                .filter(|entry| matches!(
+                    entry,
+                    ClassifiedEntry::BeginVertex(_) | ClassifiedEntry::BeginEdge(..)
+                ))
+                .collect::<Vec<ClassifiedEntry>>(),
+            vec![
+                ClassifiedEntry::BeginVertex(VertexId(0)),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(0), VertexId(1)), EdgeKind::Tree),
+                ClassifiedEntry::BeginVertex(VertexId(2)),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(2), VertexId(3)), EdgeKind::Tree),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(2), VertexId(0)), EdgeKind::Cross),
+                ClassifiedEntry::BeginVertex(VertexId(1)),
+                ClassifiedEntry::BeginVertex(VertexId(3)),
+            ]
+        );
+        // 0 is found in the tree of 2 before it is begun as a root
+        assert_eq!(
+            DepthFirst::on_all(&graph, [VertexId(2), VertexId(0), VertexId(5)])
+                .classified()
+                .filter(|entry| matches!(
+                    entry,
+                    ClassifiedEntry::BeginVertex(_) | ClassifiedEntry::BeginEdge(..)
+                ))
+                .collect::<Vec<ClassifiedEntry>>(),
+            vec![
+                ClassifiedEntry::BeginVertex(VertexId(2)),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(2), VertexId(3)), EdgeKind::Tree),
+                ClassifiedEntry::BeginVertex(VertexId(3)),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(2), VertexId(0)), EdgeKind::Tree),
+                ClassifiedEntry::BeginVertex(VertexId(0)),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(0), VertexId(1)), EdgeKind::Tree),
+                ClassifiedEntry::BeginVertex(VertexId(1)),
+            ]
+        );
+        assert_eq!(
+            DepthFirst::on_all(&graph, [VertexId(0), VertexId(2)])
+                .classified()
+                .filter(|entry| matches!(entry, ClassifiedEntry::BeginEdge(..)))
+                .collect::<Vec<ClassifiedEntry>>(),
+            vec![
+                ClassifiedEntry::BeginEdge(Edge(VertexId(0), VertexId(1)), EdgeKind::Tree),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(2), VertexId(3)), EdgeKind::Tree),
+                ClassifiedEntry::BeginEdge(Edge(VertexId(2), VertexId(0)), EdgeKind::Cross),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_many_rejected_start_vertices_in_a_row() {
+        let graph = Graph::from(200_000, vec![]).unwrap();
+        assert_eq!(
+            DepthFirst::on_all(&graph, (0..200_000).map(VertexId))
+                .filter_vertices(|_| false, RejectedVertex::NotEmitted)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn resumes_with_pending_start_vertices() {
+        let graph = Graph::from(4, vec![(0, 1), (2, 3), (2, 0)]).unwrap();
+        let mut enumeration = DepthFirst::on_all(&graph, [VertexId(3), VertexId(2), VertexId(0)]);
+        let begun = enumeration.by_ref().take(2).collect::<Vec<DFSEntry>>();
+        let checkpoint = enumeration.checkpoint();
+        assert_eq!(checkpoint.pending_starts, vec![VertexId(2), VertexId(0)]);
+        assert_eq!(
+            begun
+                .into_iter()
+                .chain(DepthFirst::resume(&graph, checkpoint))
+                .collect::<Vec<DFSEntry>>(),
+            DepthFirst::on_all(&graph, [VertexId(3), VertexId(2), VertexId(0)])
+                .collect::<Vec<DFSEntry>>()
+        );
+    }
+
+    #[test]
+    fn enumerates_edges_in_given_order() {
+        let graph = Graph::from(3, vec![(0, 2), (0, 1), (1, 2)]).unwrap();
+        assert_eq!(
+            DepthFirst::on(&graph, VertexId(0))
+                .neighbor_order(NeighborOrder::Sorted)
+                .filter(|entry| matches!(entry, DFSEntry::BeginVertex(_)))
+                .collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginVertex(VertexId(0)),
+                DFSEntry::BeginVertex(VertexId(1)),
+                DFSEntry::BeginVertex(VertexId(2)),
+            ]
+        );
+        assert_eq!(
+            BreadthFirst::on(&graph, VertexId(0))
+                .neighbor_order(NeighborOrder::Reverse)
+                .filter(|entry| matches!(entry, DFSEntry::BeginEdge(_)))
+                .collect::<Vec<DFSEntry>>(),
+            vec![
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(1))),
+                DFSEntry::BeginEdge(Edge(VertexId(0), VertexId(2))),
+                DFSEntry::BeginEdge(Edge(VertexId(1), VertexId(2))),
+            ]
+        );
+    }
+
+    fn resumes_at_each_step<'a, B: buffer::Buffer<Vertex<'a>> + 'a>(
+        graph: &'a Graph,
+        configure: impl Fn(Enumeration<'a, B>) -> Enumeration<'a, B>,
+    ) {
+        let all = configure(Enumeration::on(graph, VertexId(0)))
+            .classified()
+            .collect::<Vec<ClassifiedEntry>>();
+        for steps in 0..=all.len() {
+            let mut enumeration = configure(Enumeration::on(graph, VertexId(0)));
+            let mut entries = iter::from_fn(|| enumeration.next_classified())
+                .take(steps)
+                .collect::<Vec<ClassifiedEntry>>();
+            entries.extend(
+                configure(Enumeration::resume(graph, enumeration.checkpoint())).classified(),
+            );
+            assert_eq!(entries, all);
+        }
+    }
+
+    #[test]
+    fn resumes_exactly_from_checkpoint() {
+        let graph = Graph::from(
+            6,
+            vec![
+                (0, 1),
+                (0, 2),
+                (1, 3),
+                (2, 3),
+                (3, 0),
+                (3, 4),
+                (1, 4),
+                (4, 5),
+            ],
+        )
+        .unwrap();
+        resumes_at_each_step::<buffer::Stack<Vertex>>(&graph, |enumeration| enumeration);
+        resumes_at_each_step::<buffer::Queue<Vertex>>(&graph, |enumeration| enumeration);
+        resumes_at_each_step::<buffer::Stack<Vertex>>(&graph, |enumeration| {
+            enumeration
+                .neighbor_order(NeighborOrder::Reverse)
+                .filter_edges(|edge| *edge != Edge(VertexId(1), VertexId(4)))
+                .filter_vertices(|v| *v != VertexId(2), RejectedVertex::NotEmitted)
+        });
+    }
+
+    #[test]
+    fn resumes_pruned_vertex_as_pruned() {
+        let graph = Graph::from(4, vec![(0, 1), (1, 2), (0, 3)]).unwrap();
+        let mut enumeration = DepthFirst::on(&graph, VertexId(0));
+        iter::from_fn(|| enumeration.next_classified())
+            .take(3)
+            .count();
+        visitor::Steer::prune_current_vertex(&mut enumeration);
+        let checkpoint = enumeration.checkpoint();
+        assert!(checkpoint.buffer[0].pruned);
+        assert_eq!(
+            DepthFirst::resume(&graph, checkpoint)
+                .filter(|entry| matches!(entry, DFSEntry::BeginVertex(_)))
+                .collect::<Vec<DFSEntry>>(),
+            vec![DFSEntry::BeginVertex(VertexId(3))]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_checkpoint() {
+        let graph = Graph::from(3, vec![(0, 1), (1, 2), (2, 0)]).unwrap();
+        let mut enumeration = DepthFirst::on(&graph, VertexId(0));
+        enumeration.by_ref().take(5).count();
+        let checkpoint = enumeration.checkpoint();
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Checkpoint>(&json).unwrap(),
+            checkpoint
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_entries() {
+        let graph = Graph::from(2, vec![(0, 1), (1, 1)]).unwrap();
+        let entries = DepthFirst::on(&graph, VertexId(0)).collect::<Vec<DFSEntry>>();
+        let json = serde_json::to_string(&entries).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<DFSEntry>>(&json).unwrap(),
+            entries
+        );
+    }
 }
@@ -0,0 +1,277 @@
+//! Callbacks that are called during a detailed enumeration and that steer it
+//!
+//! A [`Visitor`] is given to the `visit` function of a detailed enumeration, e.g. [`super::tree::Enumeration::visit`]. Each callback corresponds to an entry of the enumeration, begun edges are further split by their kind.
+use super::tree::{ClassifiedEntry, EdgeKind};
+use crate::graph::{Edge, VertexId};
+
+/// Tells the enumeration how to go on after a callback of a [`Visitor`]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Control {
+    /// Goes on as usual
+    Continue,
+    /// Skips the out edges of a just begun vertex or does not follow a tree edge
+    ///
+    /// A pruned vertex is still ended. The target of a pruned tree edge is not begun, but can be found later via another edge. For all other callbacks pruning is the same as continuing.
+    Prune,
+    /// Stops the enumeration immediately
+    Stop,
+}
+
+/// Callbacks for the entries of a detailed enumeration
+///
+/// All callbacks continue the enumeration by default, such that only the relevant ones need to be implemented. Begun edges are split by their [`EdgeKind`].
+pub trait Visitor {
+    /// Called when a vertex is begun, [`Control::Prune`] skips its out edges
+    fn on_begin_vertex(&mut self, _vertex: &VertexId) -> Control {
+        Control::Continue
+    }
+    /// Called when all out edges of a vertex are done, [`Control::Prune`] is the same as continuing
+    fn on_end_vertex(&mut self, _vertex: &VertexId) -> Control {
+        Control::Continue
+    }
+    /// Called for an edge whose target vertex is found for the first time, [`Control::Prune`] does not begin the target vertex
+    fn on_tree_edge(&mut self, _edge: &Edge) -> Control {
+        Control::Continue
+    }
+    /// Called for an edge whose target vertex is begun but not yet ended
+    ///
+    /// In a depth first enumeration these are the edges that close a cycle. [`Control::Prune`] is the same as continuing.
+    fn on_back_edge(&mut self, _edge: &Edge) -> Control {
+        Control::Continue
+    }
+    /// Called for an edge to an already ended descendant of its source vertex, [`Control::Prune`] is the same as continuing
+    ///
+    /// Only depth first enumerations have forward edges.
+    fn on_forward_edge(&mut self, _edge: &Edge) -> Control {
+        Control::Continue
+    }
+    /// Called for an edge to an already found vertex that is neither an ancestor nor a descendant, [`Control::Prune`] is the same as continuing
+    fn on_cross_edge(&mut self, _edge: &Edge) -> Control {
+        Control::Continue
+    }
+    /// Called when an edge is done, together with the kind it had when it was begun, [`Control::Prune`] is the same as continuing
+    ///
+    /// In a depth first enumeration a followed tree edge is ended after its target vertex, all other edges are ended directly after they were begun.
+    fn on_end_edge(&mut self, _edge: &Edge, _kind: &EdgeKind) -> Control {
+        Control::Continue
+    }
+}
+
+// An enumeration that can be steered right after it returned an entry
+pub(super) trait Steer {
+    fn next_classified(&mut self) -> Option<ClassifiedEntry>;
+    // has to be called directly after a BeginVertex entry
+    fn prune_current_vertex(&mut self);
+    // has to be called directly after a BeginEdge entry of a tree edge
+    fn prune_current_tree_edge(&mut self);
+}
+
+pub(super) fn visit(enumeration: &mut impl Steer, visitor: &mut impl Visitor) -> Control {
+    while let Some(entry) = enumeration.next_classified() {
+        let control = match &entry {
+            ClassifiedEntry::BeginVertex(vertex) => visitor.on_begin_vertex(vertex),
+            ClassifiedEntry::EndVertex(vertex) => visitor.on_end_vertex(vertex),
+            ClassifiedEntry::BeginEdge(edge, EdgeKind::Tree) => visitor.on_tree_edge(edge),
+            ClassifiedEntry::BeginEdge(edge, EdgeKind::Back) => visitor.on_back_edge(edge),
+            ClassifiedEntry::BeginEdge(edge, EdgeKind::Forward) => visitor.on_forward_edge(edge),
+            ClassifiedEntry::BeginEdge(edge, EdgeKind::Cross) => visitor.on_cross_edge(edge),
+            ClassifiedEntry::EndEdge(edge, kind) => visitor.on_end_edge(edge, kind),
+        };
+        match (control, entry) {
+            (Control::Stop, _) => return Control::Stop,
+            (Control::Prune, ClassifiedEntry::BeginVertex(_)) => enumeration.prune_current_vertex(),
+            (Control::Prune, ClassifiedEntry::BeginEdge(_, EdgeKind::Tree)) => {
+                enumeration.prune_current_tree_edge()
+            }
+            _ => (),
+        }
+    }
+    Control::Continue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::enumeration::detailed::{graph, tree},
+        graph::Graph,
+    };
+
+    #[derive(Default)]
+    struct Recorder {
+        calls: Vec<String>,
+        prune_vertex: Option<VertexId>,
+        prune_edge: Option<Edge>,
+        stop_at: Option<VertexId>,
+    }
+    impl Visitor for Recorder {
+        fn on_begin_vertex(&mut self, vertex: &VertexId) -> Control {
+            self.calls.push(format!("begin {}", vertex.0));
+            if self.stop_at.as_ref() == Some(vertex) {
+                return Control::Stop;
+            }
+            match self.prune_vertex.as_ref() == Some(vertex) {
+                true => Control::Prune,
+                false => Control::Continue,
+            }
+        }
+        fn on_end_vertex(&mut self, vertex: &VertexId) -> Control {
+            self.calls.push(format!("end {}", vertex.0));
+            Control::Continue
+        }
+        fn on_tree_edge(&mut self, edge: &Edge) -> Control {
+            self.calls
+                .push(format!("tree {}->{}", edge.0 .0, edge.1 .0));
+            match self.prune_edge.as_ref() == Some(edge) {
+                true => Control::Prune,
+                false => Control::Continue,
+            }
+        }
+        fn on_back_edge(&mut self, edge: &Edge) -> Control {
+            self.calls
+                .push(format!("back {}->{}", edge.0 .0, edge.1 .0));
+            Control::Continue
+        }
+        fn on_forward_edge(&mut self, edge: &Edge) -> Control {
+            self.calls
+                .push(format!("forward {}->{}", edge.0 .0, edge.1 .0));
+            Control::Continue
+        }
+        fn on_cross_edge(&mut self, edge: &Edge) -> Control {
+            self.calls
+                .push(format!("cross {}->{}", edge.0 .0, edge.1 .0));
+            Control::Continue
+        }
+    }
+
+    #[test]
+    fn calls_visitor_for_each_entry() {
+        let graph = Graph::from(3, vec![(0, 1), (1, 0), (0, 2), (1, 2)]).unwrap();
+        let mut visitor = Recorder::default();
+        assert_eq!(
+            tree::DepthFirst::on(&graph, VertexId(0)).visit(&mut visitor),
+            Control::Continue
+        );
+        assert_eq!(
+            visitor.calls,
+            vec![
+                "begin 0",
+                "tree 0->1",
+                "begin 1",
+                "back 1->0",
+                "tree 1->2",
+                "begin 2",
+                "end 2",
+                "end 1",
+                "forward 0->2",
+                "end 0"
+            ]
+        );
+    }
+
+    #[test]
+    fn pruned_vertex_is_ended_without_following_its_edges() {
+        let graph = Graph::from(4, vec![(0, 1), (1, 2), (0, 3)]).unwrap();
+        let mut visitor = Recorder {
+            prune_vertex: Some(VertexId(1)),
+            ..Default::default()
+        };
+        tree::DepthFirst::on(&graph, VertexId(0)).visit(&mut visitor);
+        assert_eq!(
+            visitor.calls,
+            vec![
+                "begin 0",
+                "tree 0->1",
+                "begin 1",
+                "end 1",
+                "tree 0->3",
+                "begin 3",
+                "end 3",
+                "end 0"
+            ]
+        );
+
+        let mut visitor = Recorder {
+            prune_vertex: Some(VertexId(1)),
+            ..Default::default()
+        };
+        tree::BreadthFirst::on(&graph, VertexId(0)).visit(&mut visitor);
+        assert_eq!(
+            visitor.calls,
+            vec![
+                "begin 0",
+                "tree 0->1",
+                "tree 0->3",
+                "end 0",
+                "begin 1",
+                "end 1",
+                "begin 3",
+                "end 3"
+            ]
+        );
+    }
+
+    #[test]
+    fn target_of_pruned_tree_edge_can_be_found_via_another_edge() {
+        let graph = Graph::from(3, vec![(0, 1), (0, 2), (2, 1)]).unwrap();
+        let mut visitor = Recorder {
+            prune_edge: Some(Edge(VertexId(0), VertexId(1))),
+            ..Default::default()
+        };
+        tree::DepthFirst::on(&graph, VertexId(0)).visit(&mut visitor);
+        assert_eq!(
+            visitor.calls,
+            vec![
+                "begin 0",
+                "tree 0->1",
+                "tree 0->2",
+                "begin 2",
+                "tree 2->1",
+                "begin 1",
+                "end 1",
+                "end 2",
+                "end 0"
+            ]
+        );
+
+        let mut visitor = Recorder {
+            prune_edge: Some(Edge(VertexId(0), VertexId(1))),
+            ..Default::default()
+        };
+        tree::BreadthFirst::on(&graph, VertexId(0)).visit(&mut visitor);
+        assert_eq!(
+            visitor.calls,
+            vec![
+                "begin 0",
+                "tree 0->1",
+                "tree 0->2",
+                "end 0",
+                "begin 2",
+                "tree 2->1",
+                "end 2",
+                "begin 1",
+                "end 1"
+            ]
+        );
+    }
+
+    #[test]
+    fn stopped_enumeration_can_be_continued() {
+        let graph = Graph::from(3, vec![(0, 1), (2, 0)]).unwrap();
+        let mut enumeration = graph::DepthFirst::on(&graph);
+        let mut visitor = Recorder {
+            stop_at: Some(VertexId(1)),
+            ..Default::default()
+        };
+        assert_eq!(enumeration.visit(&mut visitor), Control::Stop);
+        assert_eq!(visitor.calls, vec!["begin 0", "tree 0->1", "begin 1"]);
+
+        visitor.calls.clear();
+        assert_eq!(enumeration.visit(&mut visitor), Control::Continue);
+        assert_eq!(
+            visitor.calls,
+            vec!["end 1", "end 0", "begin 2", "cross 2->0", "end 2"]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::graph::{Edge, VertexId};
+
+/// Defines what happens with a vertex that does not fulfill the vertex predicate of an enumeration
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RejectedVertex {
+    /// The vertex is not given, but its out edges are followed
+    NotEmitted,
+    /// The vertex is given, but its out edges are not followed
+    NotExpanded,
+}
+
+type Predicate<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+
+// Predicates that restrict an enumeration, without predicates everything is enumerated
+pub(crate) struct Filter<'a> {
+    vertex: Option<(Predicate<'a, VertexId>, RejectedVertex)>,
+    edge: Option<Predicate<'a, Edge>>,
+}
+impl<'a> Filter<'a> {
+    pub fn new() -> Self {
+        Self {
+            vertex: None,
+            edge: None,
+        }
+    }
+    pub fn with_vertices(
+        self,
+        predicate: impl Fn(&VertexId) -> bool + 'a,
+        rejected: RejectedVertex,
+    ) -> Self {
+        Self {
+            vertex: Some((Box::new(predicate), rejected)),
+            ..self
+        }
+    }
+    pub fn with_edges(self, predicate: impl Fn(&Edge) -> bool + 'a) -> Self {
+        Self {
+            edge: Some(Box::new(predicate)),
+            ..self
+        }
+    }
+
+    pub fn emits(&self, vertex: &VertexId) -> bool {
+        self.accepts(vertex, RejectedVertex::NotEmitted)
+    }
+    pub fn expands(&self, vertex: &VertexId) -> bool {
+        self.accepts(vertex, RejectedVertex::NotExpanded)
+    }
+    pub fn follows(&self, edge: &Edge) -> bool {
+        self.edge.as_ref().is_none_or(|predicate| predicate(edge))
+    }
+
+    fn accepts(&self, vertex: &VertexId, consequence: RejectedVertex) -> bool {
+        match &self.vertex {
+            Some((predicate, rejected)) if *rejected == consequence => predicate(vertex),
+            _ => true,
+        }
+    }
+}
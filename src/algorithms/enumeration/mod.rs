@@ -1,2 +1,5 @@
 pub mod basic;
 pub mod detailed;
+pub mod filter;
+pub mod order;
+pub mod paths;
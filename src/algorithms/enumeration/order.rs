@@ -0,0 +1,62 @@
+use std::{borrow::Borrow, cmp::Ordering};
+
+use crate::graph::VertexId;
+
+type Comparator<'a> = Box<dyn Fn(&VertexId, &VertexId) -> Ordering + 'a>;
+
+/// Defines the order in which an enumeration visits the out neighbours of a vertex
+///
+/// Without a given order the neighbours are visited in the order that is most natural for the enumeration, e.g. a basic depth first enumeration visits them in reverse insertion order because it pushes them onto a stack.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{DepthFirstOnTree, Graph, NeighborOrder, VertexId};
+///
+/// let graph = Graph::from(4, vec![(0, 2), (0, 1), (2, 3)]).unwrap();
+///
+/// assert_eq!(
+///     DepthFirstOnTree::on(&graph, &VertexId(0))
+///         .neighbor_order(NeighborOrder::Sorted)
+///         .collect::<Vec<&VertexId>>(),
+///     vec![&VertexId(0), &VertexId(1), &VertexId(2), &VertexId(3)]
+/// );
+/// assert_eq!(
+///     DepthFirstOnTree::on(&graph, &VertexId(0))
+///         .neighbor_order(NeighborOrder::by_key(|v| 10 - v.0))
+///         .collect::<Vec<&VertexId>>(),
+///     vec![&VertexId(0), &VertexId(2), &VertexId(3), &VertexId(1)]
+/// );
+/// ```
+pub enum NeighborOrder<'a> {
+    /// Order in which the edges were given to the graph
+    Insertion,
+    /// Ascending by vertex id
+    Sorted,
+    /// Reverse insertion order
+    Reverse,
+    /// Ascending according to a comparator, see [`NeighborOrder::by`] and [`NeighborOrder::by_key`]
+    Custom(Comparator<'a>),
+}
+impl<'a> NeighborOrder<'a> {
+    pub fn by(compare: impl Fn(&VertexId, &VertexId) -> Ordering + 'a) -> Self {
+        Self::Custom(Box::new(compare))
+    }
+    pub fn by_key<K: Ord>(key: impl Fn(&VertexId) -> K + 'a) -> Self {
+        Self::Custom(Box::new(move |a, b| key(a).cmp(&key(b))))
+    }
+
+    // Sorting is stable, such that parallel edges keep their insertion order
+    pub(crate) fn arrange<V: Borrow<VertexId>>(&self, neighbors: &mut [V]) {
+        self.arrange_by(neighbors, |v| v.borrow())
+    }
+    // Arranges items that each belong to a neighbour
+    pub(crate) fn arrange_by<T>(&self, items: &mut [T], neighbor: impl Fn(&T) -> &VertexId) {
+        match self {
+            Self::Insertion => (),
+            Self::Sorted => items.sort_by(|a, b| neighbor(a).cmp(neighbor(b))),
+            Self::Reverse => items.reverse(),
+            Self::Custom(compare) => items.sort_by(|a, b| compare(neighbor(a), neighbor(b))),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::{collections::HashSet, iter};
+
+use crate::graph::{Edge, Graph, VertexId};
+
+/// A path through the graph, given by its vertices and by the edges between them
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Path {
+    pub vertices: Vec<VertexId>,
+    pub edges: Vec<Edge>,
+}
+impl Path {
+    /// Number of edges on the path
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+    /// Last vertex of the path
+    pub fn end(&self) -> Option<&VertexId> {
+        self.vertices.last()
+    }
+}
+
+/// Defines which elements may not be repeated, like in the traversals of graph databases
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Uniqueness {
+    /// Each vertex is on at most one of all given paths, then the paths form a tree
+    VerticesGlobal,
+    /// A vertex is at most once on a path, such that all paths are simple
+    VerticesPerPath,
+    /// An edge is at most once on a path, but a path can go through a vertex several times
+    EdgesPerPath,
+    /// Anything can be repeated, the traversal only ends on graphs with cycles if a maximal depth is given
+    None,
+}
+
+/// Depth first traversal on a single tree that gives all paths from the start vertex
+///
+/// Each path is given as soon as it is found, starting with the path that only consists of the start vertex. Which paths are allowed is defined by the [`Uniqueness`], which is [`Uniqueness::EdgesPerPath`] by default.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Graph, Paths, Uniqueness, VertexId};
+///
+/// let graph = Graph::from(3, vec![(0, 1), (1, 2), (0, 2)]).unwrap();
+///
+/// assert_eq!(
+///     Paths::on(&graph, &VertexId(0))
+///         .min_depth(1)
+///         .map(|path| path.vertices)
+///         .collect::<Vec<Vec<VertexId>>>(),
+///     vec![
+///         vec![VertexId(0), VertexId(1)],
+///         vec![VertexId(0), VertexId(1), VertexId(2)],
+///         vec![VertexId(0), VertexId(2)],
+///     ]
+/// );
+/// assert_eq!(
+///     Paths::on(&graph, &VertexId(0))
+///         .uniqueness(Uniqueness::VerticesGlobal)
+///         .count(),
+///     3
+/// );
+/// ```
+pub struct Paths<'a> {
+    graph: &'a Graph,
+    uniqueness: Uniqueness,
+    min_depth: usize,
+    max_depth: Option<usize>,
+    start: Option<VertexId>,
+    path: Path,
+    // edge positions of the current path, they distinguish parallel edges
+    positions: Vec<usize>,
+    // remaining out edges of each vertex on the current path
+    out_edges: Vec<Box<dyn Iterator<Item = (usize, &'a Edge)> + 'a>>,
+    visited: HashSet<VertexId>,
+}
+impl<'a> Paths<'a> {
+    pub fn on(graph: &'a Graph, start: &VertexId) -> Self {
+        Self {
+            graph,
+            uniqueness: Uniqueness::EdgesPerPath,
+            min_depth: 0,
+            max_depth: None,
+            start: graph.contains(start).then(|| start.clone()),
+            path: Path {
+                vertices: Vec::new(),
+                edges: Vec::new(),
+            },
+            positions: Vec::new(),
+            out_edges: Vec::new(),
+            visited: HashSet::new(),
+        }
+    }
+    pub fn uniqueness(self, uniqueness: Uniqueness) -> Self {
+        Self { uniqueness, ..self }
+    }
+    /// Does not give paths with less than `depth` edges, they are still traversed
+    pub fn min_depth(self, depth: usize) -> Self {
+        Self {
+            min_depth: depth,
+            ..self
+        }
+    }
+    /// Does not extend paths with `depth` edges
+    pub fn max_depth(self, depth: usize) -> Self {
+        Self {
+            max_depth: Some(depth),
+            ..self
+        }
+    }
+
+    fn allows(&self, position: usize, vertex: &VertexId) -> bool {
+        match self.uniqueness {
+            Uniqueness::VerticesGlobal => !self.visited.contains(vertex),
+            Uniqueness::VerticesPerPath => !self.path.vertices.contains(vertex),
+            Uniqueness::EdgesPerPath => !self.positions.contains(&position),
+            Uniqueness::None => true,
+        }
+    }
+    // Gives the extended path if it is long enough
+    fn extend(&mut self, vertex: VertexId) -> Option<Path> {
+        self.visited.insert(vertex.clone());
+        self.path.vertices.push(vertex.clone());
+        let depth = self.path.len();
+        match self.max_depth.is_none_or(|max_depth| depth < max_depth) {
+            true => self.out_edges.push(Box::new(self.graph.out_edges(&vertex))),
+            false => self.out_edges.push(Box::new(iter::empty())),
+        }
+        (depth >= self.min_depth).then(|| self.path.clone())
+    }
+    fn shorten(&mut self) {
+        self.out_edges.pop();
+        self.path.vertices.pop();
+        self.path.edges.pop();
+        self.positions.pop();
+    }
+}
+
+impl<'a> Iterator for Paths<'a> {
+    type Item = Path;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(start) = self.start.take() {
+            if let Some(path) = self.extend(start) {
+                return Some(path);
+            }
+        }
+        loop {
+            match self.out_edges.last_mut()?.next() {
+                None => self.shorten(),
+                Some((position, edge)) => {
+                    if !self.allows(position, &edge.1) {
+                        continue;
+                    }
+                    self.path.edges.push(edge.clone());
+                    self.positions.push(position);
+                    if let Some(path) = self.extend(edge.1.clone()) {
+                        return Some(path);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vertices_of(paths: Paths) -> Vec<Vec<usize>> {
+        paths
+            .map(|path| path.vertices.iter().map(|v| v.0).collect())
+            .collect()
+    }
+
+    #[test]
+    fn does_not_find_non_existend_vertex() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        assert_eq!(Paths::on(&graph, &VertexId(0)).next(), None);
+    }
+
+    #[test]
+    fn gives_vertices_and_edges_of_each_path() {
+        let graph = Graph::from(3, vec![(0, 1), (1, 2)]).unwrap();
+        assert_eq!(
+            Paths::on(&graph, &VertexId(0)).last(),
+            Some(Path {
+                vertices: vec![VertexId(0), VertexId(1), VertexId(2)],
+                edges: vec![
+                    Edge(VertexId(0), VertexId(1)),
+                    Edge(VertexId(1), VertexId(2))
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn repeats_elements_according_to_uniqueness() {
+        // a cycle 0 -> 1 -> 0 with a parallel edge from 1 to 0
+        let graph = Graph::from(2, vec![(0, 1), (1, 0), (1, 0)]).unwrap();
+        assert_eq!(
+            vertices_of(Paths::on(&graph, &VertexId(0)).uniqueness(Uniqueness::VerticesGlobal)),
+            vec![vec![0], vec![0, 1]]
+        );
+        assert_eq!(
+            vertices_of(Paths::on(&graph, &VertexId(1)).uniqueness(Uniqueness::VerticesPerPath)),
+            vec![vec![1], vec![1, 0], vec![1, 0]]
+        );
+        assert_eq!(
+            vertices_of(Paths::on(&graph, &VertexId(0)).uniqueness(Uniqueness::EdgesPerPath)),
+            vec![vec![0], vec![0, 1], vec![0, 1, 0], vec![0, 1, 0],]
+        );
+        assert_eq!(
+            vertices_of(
+                Paths::on(&graph, &VertexId(0))
+                    .uniqueness(Uniqueness::None)
+                    .max_depth(3)
+            ),
+            vec![
+                vec![0],
+                vec![0, 1],
+                vec![0, 1, 0],
+                vec![0, 1, 0, 1],
+                vec![0, 1, 0],
+                vec![0, 1, 0, 1],
+            ]
+        );
+    }
+
+    #[test]
+    fn only_gives_paths_between_min_and_max_depth() {
+        let graph = Graph::from(4, vec![(0, 1), (1, 2), (2, 3)]).unwrap();
+        assert_eq!(
+            vertices_of(Paths::on(&graph, &VertexId(0)).min_depth(1).max_depth(2)),
+            vec![vec![0, 1], vec![0, 1, 2]]
+        );
+    }
+}
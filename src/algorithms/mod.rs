@@ -1,4 +1,6 @@
+pub mod chordal;
 pub mod component;
 pub mod enumeration;
 pub mod scc;
+pub mod walks;
 pub mod wcc;
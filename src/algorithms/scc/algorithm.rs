@@ -1,16 +1,17 @@
-use super::stack::Stack;
+//! Tarjan's Strongly Connected Components Algorithm
+//!
+//! The algorithm finds all strongly connected components in a graph. A strongly connected component is a set of vertices where each vertex can reach any other vertex in the component via the existing edges. Each vertex belongs to exactly one strongly connected component, therefore the components partition the graph into stronly connected subgraphs.
+//!
+//! The algorithm is based on a depth first search and is linear in the number of edges and vertices. The algorithm is executed via an iterator over components, each time next() is called on the iterator, the algorithm continues and computes the next component.
+
+use super::{stack::Stack, StronglyConnectedComponents};
 use crate::algorithms::component::Component;
-///! Tarjan's Strongly Connected Components Algorithm
-///!
-///! The algorithm finds all strongly connected components in a graph. A strongly connected component is a set of vertices where each vertex can reach any other vertex in the component via the existing edges. Each vertex belongs to exactly one strongly connected component, therefore the components partition the graph into stronly connected subgraphs.
-///!
-///! The algorithm is based on a depth first search and is linear in the number of edges and vertices. The algorithm is executed via an iterator over components, each time next() is called on the iterator, the algorithm continues and computes the next component.
 use crate::algorithms::enumeration::detailed::{graph::DepthFirst, tree::DFSEntry};
 use crate::graph::Graph;
 
 /// Includes the state of the strongly connected components computation of a graph.
 ///
-/// It includes the state of a depth first search and a stack of vertices whose component was not yet fully found.
+/// It includes the state of a depth first search and a stack of vertices whose component was not yet fully found. For very large graphs use [`super::iterative::IterativeSCC`], which gives the same components with array indexed state.
 pub struct SCC<'a> {
     dfs: DepthFirst<'a>,
     unfinished_components: Stack,
@@ -20,7 +21,7 @@ pub struct SCC<'a> {
 impl<'a> SCC<'a> {
     pub fn on(graph: &'a Graph) -> Self {
         Self {
-            dfs: DepthFirst::on(&graph),
+            dfs: DepthFirst::on(graph),
             unfinished_components: Stack::new(),
         }
     }
@@ -31,7 +32,7 @@ impl<'a> Iterator for SCC<'a> {
     type Item = Component;
 
     /// Gives the next strongly connected component of the graph.
-
+    ///
     /// Internally it iterates over the next vertices in the depth first serach until it finds the next strongly connected component. New vertices are pushed to a stack and are only popped when all vertices of its component are found. Each
     ///When the depth first search is finished processing an edge with the full subgraph it points to,  
     fn next(&mut self) -> Option<Self::Item> {
@@ -43,10 +44,8 @@ impl<'a> Iterator for SCC<'a> {
                     DFSEntry::EndEdge(e) => {
                         self.unfinished_components.update_with_minimum(e.0, e.1);
                     }
-                    DFSEntry::EndVertex(v) => {
-                        if self.unfinished_components.is_root(&v) {
-                            return Some(self.unfinished_components.pop_until(v));
-                        }
+                    DFSEntry::EndVertex(v) if self.unfinished_components.is_root(&v) => {
+                        return Some(self.unfinished_components.pop_until(v));
                     }
                     _ => (),
                 },
@@ -55,7 +54,14 @@ impl<'a> Iterator for SCC<'a> {
     }
 }
 
+impl<'a> StronglyConnectedComponents<'a> for SCC<'a> {
+    fn on(graph: &'a Graph) -> Self {
+        SCC::on(graph)
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::useless_conversion)]
 mod tests {
     use super::*;
     use crate::graph::VertexId;
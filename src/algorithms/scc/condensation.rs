@@ -0,0 +1,142 @@
+//! Condensation of a graph into the directed acyclic graph of its strongly connected components
+
+use std::collections::HashSet;
+
+use super::iterative::IterativeSCC;
+use crate::algorithms::component::Component;
+use crate::graph::{Graph, VertexId};
+
+/// The graph of the strongly connected components of a graph, see [`condensation`]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Condensation {
+    /// Graph with one vertex per component, where the vertex id is the position of the component in `components`
+    pub graph: Graph,
+    /// Components in reverse topological order, edges of `graph` only go from later to earlier components
+    pub components: Vec<Component>,
+    /// Component of each vertex of the original graph, indexed by the id of the vertex
+    pub component_of: Vec<VertexId>,
+}
+
+/// Condenses each strongly connected component of the graph into a single vertex
+///
+/// The condensed graph has an edge from one component to another if the original graph has an edge between vertices of these components, parallel edges are merged and edges inside a component are left out. The condensed graph has no cycles. The components are in the order in which Tarjan's algorithm finds them, which is a reverse topological order, see [`super::algorithm::SCC`].
+///
+/// # Examples
+///
+/// ```
+/// use graph::{condensation, Graph, VertexId};
+///
+/// // 0 <-> 1 -> 2 <-> 3, 0 -> 3
+/// let graph = Graph::from(4, vec![(0, 1), (1, 0), (1, 2), (2, 3), (3, 2), (0, 3)]).unwrap();
+///
+/// let condensation = condensation(&graph);
+///
+/// assert_eq!(condensation.graph, Graph::from(2, vec![(1, 0)]).unwrap());
+/// assert_eq!(
+///     condensation.component_of,
+///     vec![VertexId(1), VertexId(1), VertexId(0), VertexId(0)]
+/// );
+/// ```
+pub fn condensation(graph: &Graph) -> Condensation {
+    let mut scc = IterativeSCC::on(graph);
+    let mut components = Vec::new();
+    let mut component_of = vec![VertexId(0); graph.vertices().count()];
+    while let Some(vertices) = scc.next_vertices() {
+        for vertex in &vertices {
+            component_of[vertex.0] = VertexId(components.len());
+        }
+        components.push(Component::from(vertices));
+    }
+    let mut found = HashSet::new();
+    let edges = graph
+        .edges()
+        .map(|edge| (component_of[edge.0 .0].0, component_of[edge.1 .0].0))
+        .filter(|&(from, to)| from != to && found.insert((from, to)))
+        .collect();
+    Condensation {
+        graph: Graph::from(components.len(), edges)
+            .expect("components are vertices of the condensed graph"),
+        components,
+        component_of,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+
+    #[test]
+    fn empty_graph_condenses_to_empty_graph() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        let condensation = condensation(&graph);
+        assert_eq!(condensation.graph, graph);
+        assert!(condensation.components.is_empty());
+        assert!(condensation.component_of.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_condensation() {
+        let graph = Graph::from(3, vec![(0, 1), (1, 0), (1, 2)]).unwrap();
+        let condensation = condensation(&graph);
+        let json = serde_json::to_string(&condensation).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Condensation>(&json).unwrap(),
+            condensation
+        );
+    }
+
+    #[test]
+    fn acyclic_graph_keeps_its_edges_once() {
+        let graph = Graph::from(3, vec![(0, 1), (0, 1), (1, 2), (0, 2)]).unwrap();
+        let condensation = condensation(&graph);
+        assert_eq!(
+            condensation.component_of,
+            vec![VertexId(2), VertexId(1), VertexId(0)]
+        );
+        assert_eq!(
+            condensation.graph,
+            Graph::from(3, vec![(2, 1), (1, 0), (2, 0)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn condenses_into_acyclic_graph_in_reverse_topological_order() {
+        let mut rng = Rng::seed_from(17);
+        for _ in 0..100 {
+            let vertex_count = 1 + rng.below(12);
+            let edge_count = rng.below(3 * vertex_count);
+            let edges = (0..edge_count)
+                .map(|_| (rng.below(vertex_count), rng.below(vertex_count)))
+                .collect::<Vec<(usize, usize)>>();
+            let graph = Graph::from(vertex_count, edges.clone()).unwrap();
+            let condensation = condensation(&graph);
+            let condensed = condensation
+                .graph
+                .edges()
+                .map(|edge| (edge.0 .0, edge.1 .0))
+                .collect::<Vec<(usize, usize)>>();
+            assert!(condensed.iter().all(|(from, to)| from > to));
+            assert_eq!(
+                condensed.len(),
+                condensed.iter().collect::<HashSet<_>>().len()
+            );
+            for (from, to) in edges {
+                let (from, to) = (
+                    &condensation.component_of[from],
+                    &condensation.component_of[to],
+                );
+                assert!(from == to || condensation.graph.has_edge(from, to));
+            }
+            for (index, component) in condensation.components.iter().enumerate() {
+                let vertices = (0..vertex_count)
+                    .map(VertexId)
+                    .filter(|vertex| condensation.component_of[vertex.0].0 == index)
+                    .collect();
+                assert_eq!(*component, Component::from(vertices));
+            }
+        }
+    }
+}
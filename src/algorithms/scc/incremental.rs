@@ -0,0 +1,383 @@
+//! Strongly connected components of a graph that grows by edge insertions
+//!
+//! The components are kept as the vertices of their condensed graph, together with a topological order of that graph as in the algorithm of Pearce and Kelly (see <https://doi.org/10.1145/1187436.1210590>). An inserted edge that agrees with the order changes nothing. Otherwise only the components between both ends of the edge in the order are searched: The components that are reachable from the head of the edge and reach its tail form a cycle with the edge and are merged, the others are reordered.
+
+use std::collections::HashSet;
+
+use super::condensation::condensation;
+use crate::{
+    algorithms::{
+        component::{Component, Partition},
+        wcc::{disjoint_set::DisjointSet, union_find::Error},
+    },
+    graph::{Graph, VertexId},
+};
+
+/// Components that were merged into one by an inserted edge, see [`IncrementalSCC::add_edge`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Merge {
+    /// Representatives of the merged components before the insertion, in topological order
+    pub merged: Vec<VertexId>,
+    /// Representative of the merged component
+    pub into: VertexId,
+}
+
+/// Strongly connected components of a growing graph
+///
+/// Each component is identified by a representative vertex, which stays the same until the component is merged with others. The components are kept in a topological order of the condensed graph, such that an edge between components is only expensive if it goes against that order. Only the components and the edges between them are kept, not the edges inside of components.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Graph, IncrementalSCC, VertexId};
+///
+/// // 0 -> 1 -> 2
+/// let graph = Graph::from(3, vec![(0, 1), (1, 2)]).unwrap();
+/// let mut scc = IncrementalSCC::on(&graph);
+/// assert_eq!(scc.num_components(), 3);
+///
+/// assert_eq!(scc.add_edge(&VertexId(0), &VertexId(2)), Ok(None));
+/// let merge = scc.add_edge(&VertexId(2), &VertexId(0)).unwrap().unwrap();
+/// assert_eq!(merge.merged.len(), 3);
+///
+/// assert_eq!(scc.num_components(), 1);
+/// assert!(scc.strongly_connected(&VertexId(0), &VertexId(2)));
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncrementalSCC {
+    sets: DisjointSet,
+    // the following entries are only up to date for the representatives
+    members: Vec<Vec<VertexId>>,
+    successors: Vec<HashSet<usize>>,
+    predecessors: Vec<HashSet<usize>>,
+    // position in the topological order, edges between components only go to larger positions
+    position: Vec<usize>,
+    // whether a representative was found by the current search
+    marked: Vec<bool>,
+}
+impl IncrementalSCC {
+    /// Starts with the vertices `0..vertex_count` without edges
+    pub fn new(vertex_count: usize) -> Self {
+        Self {
+            sets: DisjointSet::new(vertex_count),
+            members: (0..vertex_count).map(|v| vec![VertexId(v)]).collect(),
+            successors: vec![HashSet::new(); vertex_count],
+            predecessors: vec![HashSet::new(); vertex_count],
+            position: (0..vertex_count).collect(),
+            marked: vec![false; vertex_count],
+        }
+    }
+    /// Starts with the components of the graph, which are found with [`condensation`]
+    pub fn on(graph: &Graph) -> Self {
+        let condensation = condensation(graph);
+        let mut scc = Self::new(condensation.component_of.len());
+        let mut representatives = Vec::new();
+        for component in &condensation.components {
+            let mut vertices = component.iter();
+            let first = vertices.next().expect("components are not empty");
+            vertices.for_each(|vertex| {
+                scc.sets.union(first, vertex);
+            });
+            let representative = scc.sets.find(first).0;
+            scc.members[representative] = component.iter().cloned().collect();
+            representatives.push(representative);
+        }
+        // the components of the condensation are in reverse topological order
+        for (index, &representative) in representatives.iter().enumerate() {
+            scc.position[representative] = representatives.len() - 1 - index;
+        }
+        for edge in condensation.graph.edges() {
+            let (from, to) = (representatives[edge.0 .0], representatives[edge.1 .0]);
+            scc.successors[from].insert(to);
+            scc.predecessors[to].insert(from);
+        }
+        scc
+    }
+    /// Adds the next vertex as a component of its own and gives it
+    pub fn add_vertex(&mut self) -> VertexId {
+        let vertex = self.sets.add();
+        self.members.push(vec![vertex.clone()]);
+        self.successors.push(HashSet::new());
+        self.predecessors.push(HashSet::new());
+        // all positions are smaller than the number of vertices
+        self.position.push(vertex.0);
+        self.marked.push(false);
+        vertex
+    }
+    /// Adds an edge, gives the components that it merged into one, if any
+    pub fn add_edge(&mut self, from: &VertexId, to: &VertexId) -> Result<Option<Merge>, Error> {
+        self.check(from)?;
+        self.check(to)?;
+        let (tail, head) = (self.sets.find(from).0, self.sets.find(to).0);
+        if tail == head {
+            return Ok(None);
+        }
+        if self.position[tail] < self.position[head] {
+            self.successors[tail].insert(head);
+            self.predecessors[head].insert(tail);
+            return Ok(None);
+        }
+        let (lower, upper) = (self.position[head], self.position[tail]);
+        let forward = self.search(head, |scc, c| {
+            scc.successors[c]
+                .iter()
+                .copied()
+                .filter(|&s| scc.position[s] <= upper)
+                .collect()
+        });
+        let backward = self.search(tail, |scc, c| {
+            scc.predecessors[c]
+                .iter()
+                .copied()
+                .filter(|&p| scc.position[p] >= lower)
+                .collect()
+        });
+        let in_forward = forward.iter().copied().collect::<HashSet<usize>>();
+        let (mut cycle, mut before): (Vec<usize>, Vec<usize>) = backward
+            .iter()
+            .copied()
+            .partition(|c| in_forward.contains(c));
+        let in_backward = backward.iter().copied().collect::<HashSet<usize>>();
+        let mut after = forward
+            .into_iter()
+            .filter(|c| !in_backward.contains(c))
+            .collect::<Vec<usize>>();
+        let mut positions = before
+            .iter()
+            .chain(&cycle)
+            .chain(&after)
+            .map(|&c| self.position[c])
+            .collect::<Vec<usize>>();
+        positions.sort_unstable();
+        for components in [&mut before, &mut cycle, &mut after] {
+            components.sort_unstable_by_key(|&c| self.position[c]);
+        }
+        if cycle.is_empty() {
+            self.successors[tail].insert(head);
+            self.predecessors[head].insert(tail);
+            self.reorder(before.into_iter().chain(after), positions);
+            return Ok(None);
+        }
+        let into = self.merge(&cycle);
+        let positions = positions[..before.len() + 1]
+            .iter()
+            .chain(&positions[positions.len() - after.len()..])
+            .copied()
+            .collect();
+        self.reorder(before.into_iter().chain([into]).chain(after), positions);
+        Ok(Some(Merge {
+            merged: cycle.into_iter().map(VertexId).collect(),
+            into: VertexId(into),
+        }))
+    }
+    /// Gives the representative of the component of the vertex
+    pub fn representative(&mut self, vertex: &VertexId) -> Result<VertexId, Error> {
+        self.check(vertex)?;
+        Ok(self.sets.find(vertex))
+    }
+    /// Checks whether both vertices are in the same component, which is false if one of them was not added
+    pub fn strongly_connected(&mut self, first: &VertexId, second: &VertexId) -> bool {
+        self.includes(first) && self.includes(second) && self.sets.same_set(first, second)
+    }
+    /// Gives the vertices of the component of the vertex
+    pub fn component(&mut self, vertex: &VertexId) -> Result<Component, Error> {
+        let representative = self.representative(vertex)?;
+        Ok(Component::from(self.members[representative.0].clone()))
+    }
+    pub fn num_components(&self) -> usize {
+        self.sets.num_sets()
+    }
+    /// Number of added vertices
+    pub fn num_vertices(&self) -> usize {
+        self.sets.len()
+    }
+    /// Gives all current components in topological order, edges only go from earlier to later components
+    pub fn components(&mut self) -> Partition {
+        let mut representatives = (0..self.sets.len())
+            .filter(|&v| self.sets.find(&VertexId(v)).0 == v)
+            .collect::<Vec<usize>>();
+        representatives.sort_unstable_by_key(|&r| self.position[r]);
+        representatives
+            .into_iter()
+            .map(|r| Component::from(self.members[r].clone()))
+            .collect()
+    }
+
+    // Gives all representatives that are reachable from the start with the given steps
+    fn search(&mut self, start: usize, next: impl Fn(&Self, usize) -> Vec<usize>) -> Vec<usize> {
+        let mut found = vec![start];
+        let mut stack = vec![start];
+        self.marked[start] = true;
+        while let Some(component) = stack.pop() {
+            for neighbour in next(self, component) {
+                if !self.marked[neighbour] {
+                    self.marked[neighbour] = true;
+                    found.push(neighbour);
+                    stack.push(neighbour);
+                }
+            }
+        }
+        found.iter().for_each(|&c| self.marked[c] = false);
+        found
+    }
+    // Unites the components and their edges, gives the new representative
+    fn merge(&mut self, components: &[usize]) -> usize {
+        for component in &components[1..] {
+            self.sets
+                .union(&VertexId(components[0]), &VertexId(*component));
+        }
+        let into = self.sets.find(&VertexId(components[0])).0;
+        let merged = components.iter().copied().collect::<HashSet<usize>>();
+        for &component in components.iter().filter(|&&c| c != into) {
+            let members = std::mem::take(&mut self.members[component]);
+            self.members[into].extend(members);
+            for successor in std::mem::take(&mut self.successors[component]) {
+                self.predecessors[successor].remove(&component);
+                if !merged.contains(&successor) {
+                    self.predecessors[successor].insert(into);
+                    self.successors[into].insert(successor);
+                }
+            }
+            for predecessor in std::mem::take(&mut self.predecessors[component]) {
+                self.successors[predecessor].remove(&component);
+                if !merged.contains(&predecessor) {
+                    self.successors[predecessor].insert(into);
+                    self.predecessors[into].insert(predecessor);
+                }
+            }
+        }
+        self.successors[into].retain(|c| !merged.contains(c));
+        self.predecessors[into].retain(|c| !merged.contains(c));
+        into
+    }
+    fn reorder(&mut self, components: impl Iterator<Item = usize>, positions: Vec<usize>) {
+        for (component, position) in components.zip(positions) {
+            self.position[component] = position;
+        }
+    }
+    fn includes(&self, vertex: &VertexId) -> bool {
+        vertex.0 < self.sets.len()
+    }
+    fn check(&self, vertex: &VertexId) -> Result<(), Error> {
+        match self.includes(vertex) {
+            true => Ok(()),
+            false => Err(Error::VertexNotIncluded(vertex.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::scc::algorithm::SCC, random::Rng};
+
+    // Checks the components against a recomputation and their order against the edges
+    fn agrees_with_recomputation(
+        scc: &mut IncrementalSCC,
+        vertex_count: usize,
+        edges: &[(usize, usize)],
+    ) {
+        let graph = Graph::from(vertex_count, edges.to_vec()).unwrap();
+        let expected = SCC::on(&graph).collect::<Partition>();
+        let partition = scc.components();
+        assert_eq!(partition.len(), expected.len());
+        assert_eq!(scc.num_components(), expected.len());
+        for vertex in (0..vertex_count).map(VertexId) {
+            assert_eq!(partition.component(&vertex), expected.component(&vertex));
+            assert_eq!(
+                scc.component(&vertex).ok().as_ref(),
+                expected.component(&vertex)
+            );
+        }
+        for &(from, to) in edges {
+            assert!(
+                partition.component_of(&VertexId(from)) <= partition.component_of(&VertexId(to))
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_vertices_that_were_not_added() {
+        let mut scc = IncrementalSCC::new(1);
+        assert_eq!(
+            scc.add_edge(&VertexId(1), &VertexId(0)),
+            Err(Error::VertexNotIncluded(VertexId(1)))
+        );
+        assert!(!scc.strongly_connected(&VertexId(0), &VertexId(1)));
+        assert_eq!(scc.add_vertex(), VertexId(1));
+        assert_eq!(scc.add_edge(&VertexId(1), &VertexId(0)), Ok(None));
+        let merge = scc.add_edge(&VertexId(0), &VertexId(1)).unwrap().unwrap();
+        assert_eq!(merge.merged, vec![VertexId(1), VertexId(0)]);
+        assert_eq!(scc.representative(&VertexId(1)), Ok(merge.into));
+    }
+
+    #[test]
+    fn keeps_loops_and_parallel_edges_out_of_condensation() {
+        let mut scc = IncrementalSCC::new(2);
+        assert_eq!(scc.add_edge(&VertexId(0), &VertexId(0)), Ok(None));
+        assert_eq!(scc.add_edge(&VertexId(1), &VertexId(0)), Ok(None));
+        assert_eq!(scc.add_edge(&VertexId(1), &VertexId(0)), Ok(None));
+        assert_eq!(scc.num_components(), 2);
+        assert_eq!(
+            scc.components().into_components(),
+            vec![
+                Component::from(vec![VertexId(1)]),
+                Component::from(vec![VertexId(0)])
+            ]
+        );
+    }
+
+    #[test]
+    fn starts_with_components_of_graph() {
+        // 0 <-> 1 -> 2 <-> 3
+        let graph = Graph::from(4, vec![(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)]).unwrap();
+        let mut scc = IncrementalSCC::on(&graph);
+        assert_eq!(scc.num_components(), 2);
+        let merge = scc.add_edge(&VertexId(3), &VertexId(0)).unwrap().unwrap();
+        assert_eq!(merge.merged.len(), 2);
+        assert_eq!(scc.num_components(), 1);
+        assert_eq!(scc.component(&VertexId(2)).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn agrees_with_recomputation_after_each_insertion() {
+        let mut rng = Rng::seed_from(50);
+        for _ in 0..60 {
+            let vertex_count = 1 + rng.below(12);
+            let mut edges = (0..rng.below(vertex_count))
+                .map(|_| (rng.below(vertex_count), rng.below(vertex_count)))
+                .collect::<Vec<(usize, usize)>>();
+            let mut scc = IncrementalSCC::on(&Graph::from(vertex_count, edges.clone()).unwrap());
+            agrees_with_recomputation(&mut scc, vertex_count, &edges);
+            for _ in 0..3 * vertex_count {
+                let edge = (rng.below(vertex_count), rng.below(vertex_count));
+                let before = scc.components();
+                edges.push(edge);
+                let merge = scc.add_edge(&VertexId(edge.0), &VertexId(edge.1)).unwrap();
+                agrees_with_recomputation(&mut scc, vertex_count, &edges);
+                let after = scc.components();
+                match merge {
+                    None => assert_eq!(before.len(), after.len()),
+                    Some(merge) => {
+                        assert_eq!(merge.merged.len(), before.len() - after.len() + 1);
+                        let merged = after.component(&merge.into).unwrap();
+                        for representative in &merge.merged {
+                            let component = before.component(representative).unwrap();
+                            assert!(component.iter().all(|v| merged.contains(v)));
+                        }
+                        assert_eq!(
+                            merge
+                                .merged
+                                .iter()
+                                .map(|r| before.component(r).unwrap().len())
+                                .sum::<usize>(),
+                            merged.len()
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
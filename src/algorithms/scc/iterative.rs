@@ -0,0 +1,175 @@
+//! Tarjan's Strongly Connected Components Algorithm for large graphs
+//!
+//! This is the same algorithm as [`super::algorithm::SCC`] and gives the same components in the same order, but it trades readability for speed: Instead of the detailed depth first search and maps from vertex ids, it keeps its own call stack and stores the index and the low link of each vertex in arrays that are indexed by the vertex id. Apart from the components, it allocates a fixed number of arrays of the size of the graph once.
+
+use std::cmp;
+
+use super::StronglyConnectedComponents;
+use crate::algorithms::component::Component;
+use crate::graph::{Graph, VertexId};
+
+const UNVISITED: usize = usize::MAX;
+
+/// Iterative variant of [`super::algorithm::SCC`] with array indexed state
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Component, Graph, IterativeSCC, VertexId, SCC};
+///
+/// let graph = Graph::from(4, vec![(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)]).unwrap();
+///
+/// assert_eq!(
+///     IterativeSCC::on(&graph).collect::<Vec<Component>>(),
+///     SCC::on(&graph).collect::<Vec<Component>>()
+/// );
+/// ```
+pub struct IterativeSCC<'a> {
+    graph: &'a Graph,
+    index: Vec<usize>,
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    unfinished: Vec<usize>,
+    // vertices of the current search path together with the position of their next out edge
+    path: Vec<(usize, usize)>,
+    next_index: usize,
+    next_root: usize,
+}
+impl<'a> IterativeSCC<'a> {
+    pub fn on(graph: &'a Graph) -> Self {
+        let vertex_count = graph.vertices().count();
+        Self {
+            graph,
+            index: vec![UNVISITED; vertex_count],
+            low_link: vec![UNVISITED; vertex_count],
+            on_stack: vec![false; vertex_count],
+            unfinished: Vec::new(),
+            path: Vec::new(),
+            next_index: 0,
+            next_root: 0,
+        }
+    }
+
+    fn begin(&mut self, vertex: usize) {
+        self.index[vertex] = self.next_index;
+        self.low_link[vertex] = self.next_index;
+        self.next_index += 1;
+        self.on_stack[vertex] = true;
+        self.unfinished.push(vertex);
+        self.path.push((vertex, 0));
+    }
+    fn pop_component(&mut self, root: usize) -> Vec<VertexId> {
+        let mut component = Vec::new();
+        while let Some(vertex) = self.unfinished.pop() {
+            self.on_stack[vertex] = false;
+            component.push(VertexId(vertex));
+            if vertex == root {
+                break;
+            }
+        }
+        component
+    }
+
+    // Gives the vertices of the next component, such that they can be looked at before they
+    // are put into a component
+    pub(crate) fn next_vertices(&mut self) -> Option<Vec<VertexId>> {
+        let graph = self.graph;
+        loop {
+            let Some((vertex, edge)) = self.path.last_mut() else {
+                let root = (self.next_root..self.index.len())
+                    .find(|&vertex| self.index[vertex] == UNVISITED)?;
+                self.next_root = root + 1;
+                self.begin(root);
+                continue;
+            };
+            let vertex = *vertex;
+            let neighbors = graph.out_neighbor_slice(&VertexId(vertex));
+            if let Some(neighbor) = neighbors.get(*edge) {
+                *edge += 1;
+                if self.index[neighbor.0] == UNVISITED {
+                    self.begin(neighbor.0);
+                } else if self.on_stack[neighbor.0] {
+                    self.low_link[vertex] = cmp::min(self.low_link[vertex], self.index[neighbor.0]);
+                }
+                continue;
+            }
+            self.path.pop();
+            if let Some(&(parent, _)) = self.path.last() {
+                self.low_link[parent] = cmp::min(self.low_link[parent], self.low_link[vertex]);
+            }
+            if self.low_link[vertex] == self.index[vertex] {
+                return Some(self.pop_component(vertex));
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for IterativeSCC<'a> {
+    type Item = Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_vertices().map(Component::from)
+    }
+}
+
+impl<'a> StronglyConnectedComponents<'a> for IterativeSCC<'a> {
+    fn on(graph: &'a Graph) -> Self {
+        IterativeSCC::on(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{random::Rng, SCC};
+
+    fn gives_same_components_as_readable_version(graph: &Graph) {
+        assert_eq!(
+            IterativeSCC::on(graph).collect::<Vec<Component>>(),
+            SCC::on(graph).collect::<Vec<Component>>()
+        );
+    }
+
+    #[test]
+    fn empty_graph_has_no_components() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        assert_eq!(IterativeSCC::on(&graph).next(), None);
+    }
+
+    #[test]
+    fn gives_components_in_same_order_as_readable_version() {
+        gives_same_components_as_readable_version(
+            &Graph::from(6, vec![(4, 2), (2, 0), (1, 3), (5, 2), (0, 4)]).unwrap(),
+        );
+        gives_same_components_as_readable_version(
+            &Graph::from(4, vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 2), (3, 3)]).unwrap(),
+        );
+        let mut rng = Rng::seed_from(5);
+        for _ in 0..200 {
+            let vertex_count = 1 + rng.below(15);
+            let edge_count = rng.below(3 * vertex_count);
+            let edges = (0..edge_count)
+                .map(|_| (rng.below(vertex_count), rng.below(vertex_count)))
+                .collect();
+            gives_same_components_as_readable_version(&Graph::from(vertex_count, edges).unwrap());
+        }
+    }
+
+    #[test]
+    fn handles_deep_paths_without_recursion() {
+        let vertex_count = 100_000;
+        let graph = Graph::from(
+            vertex_count,
+            (0..vertex_count)
+                .map(|v| (v, (v + 1) % vertex_count))
+                .collect(),
+        )
+        .unwrap();
+        let components = IterativeSCC::on(&graph).collect::<Vec<Component>>();
+        assert_eq!(components.len(), 1);
+        assert_eq!(
+            components[0],
+            Component::from((0..vertex_count).map(VertexId).collect())
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Kosaraju's Strongly Connected Components Algorithm
+//!
+//! The algorithm makes two depth first searches: The first one on the graph gives the order in which the vertices are ended. The second one runs on the transposed graph, where all edges are reversed, and starts its trees in reverse end order. Each of these trees is a strongly connected component, because from a vertex that was ended late in the first search the transposed graph only leads to vertices of its own component or of components that were already found.
+//!
+//! The algorithm is linear in the number of edges and vertices. The first search is executed when the algorithm is created, the second one continues each time next() is called and gives the components in topological order.
+
+use super::StronglyConnectedComponents;
+use crate::algorithms::component::Component;
+use crate::algorithms::enumeration::detailed::{graph::DepthFirst, tree::DFSEntry};
+use crate::graph::{Graph, VertexId};
+
+pub struct KosarajuSCC {
+    // in-neighbours of each vertex, the adjacency of the transposed graph
+    transposed: Vec<Vec<usize>>,
+    // vertices in the order in which the first search ended them
+    ended: Vec<usize>,
+    assigned: Vec<bool>,
+}
+impl KosarajuSCC {
+    pub fn on(graph: &Graph) -> Self {
+        let mut transposed = vec![Vec::new(); graph.vertices().count()];
+        for edge in graph.edges() {
+            transposed[edge.1 .0].push(edge.0 .0);
+        }
+        let ended = DepthFirst::on(graph)
+            .filter_map(|entry| match entry {
+                DFSEntry::EndVertex(v) => Some(v.0),
+                _ => None,
+            })
+            .collect::<Vec<usize>>();
+        Self {
+            assigned: vec![false; transposed.len()],
+            transposed,
+            ended,
+        }
+    }
+}
+
+impl Iterator for KosarajuSCC {
+    type Item = Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let root = loop {
+            let vertex = self.ended.pop()?;
+            if !self.assigned[vertex] {
+                break vertex;
+            }
+        };
+        let mut component = Component::new();
+        let mut stack = vec![root];
+        self.assigned[root] = true;
+        while let Some(vertex) = stack.pop() {
+            component.add(VertexId(vertex));
+            for &neighbour in &self.transposed[vertex] {
+                if !self.assigned[neighbour] {
+                    self.assigned[neighbour] = true;
+                    stack.push(neighbour);
+                }
+            }
+        }
+        Some(component)
+    }
+}
+
+impl<'a> StronglyConnectedComponents<'a> for KosarajuSCC {
+    fn on(graph: &'a Graph) -> Self {
+        KosarajuSCC::on(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_graph_has_no_components() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        assert_eq!(KosarajuSCC::on(&graph).next(), None);
+    }
+
+    #[test]
+    fn gives_components_in_topological_order() {
+        let graph = Graph::from(5, vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)]).unwrap();
+        assert_eq!(
+            KosarajuSCC::on(&graph).collect::<Vec<Component>>(),
+            vec![
+                Component::from(vec![VertexId(2), VertexId(1), VertexId(0)]),
+                Component::from(vec![VertexId(4), VertexId(3)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_cross_into_found_components() {
+        // the transposed edge 1 -> 0 leads back into the component of 0
+        let graph = Graph::from(3, vec![(0, 1), (1, 2), (2, 1)]).unwrap();
+        assert_eq!(
+            KosarajuSCC::on(&graph).collect::<Vec<Component>>(),
+            vec![
+                Component::from(vec![VertexId(0)]),
+                Component::from(vec![VertexId(1), VertexId(2)]),
+            ]
+        );
+    }
+}
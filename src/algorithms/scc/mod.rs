@@ -1,2 +1,116 @@
+//! Algorithms for strongly connected components
+//!
+//! All algorithms give the same partition of the vertices into strongly connected components, but they find the components in different orders: [`algorithm::SCC`] (Tarjan) and [`path_based::PathBasedSCC`] (Gabow) give them in reverse topological order of the condensed graph, [`kosaraju::KosarajuSCC`] gives them in topological order. [`iterative::IterativeSCC`] is a faster variant of Tarjan's algorithm for large graphs.
+use crate::{algorithms::component::Component, graph::Graph};
+
 pub mod algorithm;
+pub mod condensation;
+pub mod incremental;
+pub mod iterative;
+pub mod kosaraju;
+pub mod path_based;
 mod stack;
+
+/// Common interface of the strongly connected components algorithms, such that they can be exchanged
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Component, Graph, KosarajuSCC, PathBasedSCC, StronglyConnectedComponents, SCC};
+///
+/// fn count<'a, S: StronglyConnectedComponents<'a>>(graph: &'a Graph) -> usize {
+///     S::on(graph).count()
+/// }
+///
+/// let graph = Graph::from(3, vec![(0, 1), (1, 0), (1, 2)]).unwrap();
+///
+/// assert_eq!(count::<SCC>(&graph), 2);
+/// assert_eq!(count::<KosarajuSCC>(&graph), 2);
+/// assert_eq!(count::<PathBasedSCC>(&graph), 2);
+/// ```
+pub trait StronglyConnectedComponents<'a>: Iterator<Item = Component> + Sized {
+    fn on(graph: &'a Graph) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        algorithm::SCC, iterative::IterativeSCC, kosaraju::KosarajuSCC, path_based::PathBasedSCC, *,
+    };
+    use crate::random::Rng;
+
+    fn components<'a, S: StronglyConnectedComponents<'a>>(graph: &'a Graph) -> Vec<Component> {
+        <S as StronglyConnectedComponents>::on(graph).collect()
+    }
+    fn same_partition(first: &[Component], second: &[Component]) -> bool {
+        first.len() == second.len() && first.iter().all(|component| second.contains(component))
+    }
+    fn all_give_same_partition(graph: &Graph) {
+        let tarjan = components::<SCC>(graph);
+        assert!(same_partition(&tarjan, &components::<KosarajuSCC>(graph)));
+        assert!(same_partition(&tarjan, &components::<PathBasedSCC>(graph)));
+        assert!(same_partition(&tarjan, &components::<IterativeSCC>(graph)));
+    }
+
+    #[test]
+    fn give_same_partition_on_small_graphs() {
+        all_give_same_partition(&Graph::from(0, vec![]).unwrap());
+        all_give_same_partition(&Graph::from(1, vec![]).unwrap());
+        all_give_same_partition(&Graph::from(1, vec![(0, 0)]).unwrap());
+        all_give_same_partition(&Graph::from(2, vec![(0, 1)]).unwrap());
+        all_give_same_partition(&Graph::from(2, vec![(0, 1), (1, 0)]).unwrap());
+        all_give_same_partition(&Graph::from(3, vec![(2, 1), (1, 2)]).unwrap());
+        all_give_same_partition(
+            &Graph::from(6, vec![(4, 2), (2, 0), (1, 3), (5, 2), (0, 4)]).unwrap(),
+        );
+        all_give_same_partition(
+            &Graph::from(5, vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)]).unwrap(),
+        );
+    }
+
+    #[test]
+    fn give_same_partition_on_knuth_example() {
+        let graph = Graph::from(
+            10,
+            vec![
+                (3, 1),
+                (4, 1),
+                (5, 9),
+                (2, 6),
+                (5, 3),
+                (5, 8),
+                (9, 7),
+                (9, 3),
+                (2, 3),
+                (8, 4),
+                (6, 2),
+                (6, 4),
+                (3, 3),
+                (8, 3),
+                (2, 7),
+                (9, 5),
+                (0, 2),
+                (8, 8),
+                (4, 1),
+                (9, 7),
+                (1, 6),
+            ],
+        )
+        .unwrap();
+        assert_eq!(components::<KosarajuSCC>(&graph).len(), 5);
+        all_give_same_partition(&graph);
+    }
+
+    #[test]
+    fn give_same_partition_on_random_graphs() {
+        let mut rng = Rng::seed_from(11);
+        for _ in 0..200 {
+            let vertex_count = 1 + rng.below(12);
+            let edge_count = rng.below(3 * vertex_count);
+            let edges = (0..edge_count)
+                .map(|_| (rng.below(vertex_count), rng.below(vertex_count)))
+                .collect();
+            all_give_same_partition(&Graph::from(vertex_count, edges).unwrap());
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Gabow's Path-Based Strongly Connected Components Algorithm
+//!
+//! Like Tarjan's algorithm, the algorithm is based on a depth first search and pushes begun vertices onto a stack until their component is complete. Instead of low links it keeps a second stack with the roots of the components on the current search path: An edge to a begun vertex whose component is not yet found closes a cycle, so all roots that were begun after that vertex are merged into its component and are popped. A vertex that is still on top of the root stack when it is ended is the root of a component.
+//!
+//! The algorithm is linear in the number of edges and vertices. It continues each time next() is called and gives the components in reverse topological order, just like Tarjan's algorithm.
+
+use super::StronglyConnectedComponents;
+use crate::algorithms::component::Component;
+use crate::algorithms::enumeration::detailed::{graph::DepthFirst, tree::DFSEntry};
+use crate::graph::{Graph, VertexId};
+
+pub struct PathBasedSCC<'a> {
+    dfs: DepthFirst<'a>,
+    // position in which each vertex was begun, removed when its component is found
+    preorder: Vec<Option<usize>>,
+    begun: usize,
+    unfinished: Vec<VertexId>,
+    roots: Vec<VertexId>,
+}
+impl<'a> PathBasedSCC<'a> {
+    pub fn on(graph: &'a Graph) -> Self {
+        Self {
+            dfs: DepthFirst::on(graph),
+            preorder: vec![None; graph.vertices().count()],
+            begun: 0,
+            unfinished: Vec::new(),
+            roots: Vec::new(),
+        }
+    }
+
+    fn preorder_of(&self, vertex: &VertexId) -> Option<usize> {
+        self.preorder[vertex.0]
+    }
+    fn pop_component(&mut self, root: &VertexId) -> Component {
+        self.roots.pop();
+        let mut component = Component::new();
+        while let Some(vertex) = self.unfinished.pop() {
+            self.preorder[vertex.0] = None;
+            let is_root = vertex == *root;
+            component.add(vertex);
+            if is_root {
+                break;
+            }
+        }
+        component
+    }
+}
+
+impl<'a> Iterator for PathBasedSCC<'a> {
+    type Item = Component;
+
+    // A tree edge is begun before its target vertex, therefore only edges to vertices with
+    // a preorder close cycles
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.dfs.next()? {
+                DFSEntry::BeginVertex(v) => {
+                    self.preorder[v.0] = Some(self.begun);
+                    self.begun += 1;
+                    self.unfinished.push(v.clone());
+                    self.roots.push(v);
+                }
+                DFSEntry::BeginEdge(e) => {
+                    if let Some(target) = self.preorder_of(&e.1) {
+                        while self
+                            .roots
+                            .last()
+                            .and_then(|root| self.preorder_of(root))
+                            .is_some_and(|root| root > target)
+                        {
+                            self.roots.pop();
+                        }
+                    }
+                }
+                DFSEntry::EndVertex(v) if self.roots.last() == Some(&v) => {
+                    return Some(self.pop_component(&v));
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+impl<'a> StronglyConnectedComponents<'a> for PathBasedSCC<'a> {
+    fn on(graph: &'a Graph) -> Self {
+        PathBasedSCC::on(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_graph_has_no_components() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        assert_eq!(PathBasedSCC::on(&graph).next(), None);
+    }
+
+    #[test]
+    fn gives_components_in_reverse_topological_order() {
+        let graph = Graph::from(5, vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3)]).unwrap();
+        assert_eq!(
+            PathBasedSCC::on(&graph).collect::<Vec<Component>>(),
+            vec![
+                Component::from(vec![VertexId(4), VertexId(3)]),
+                Component::from(vec![VertexId(2), VertexId(1), VertexId(0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn merges_roots_of_nested_cycles() {
+        // 0 -> 1 -> 2 -> 1 and 2 -> 3 -> 0
+        let graph = Graph::from(4, vec![(0, 1), (1, 2), (2, 1), (2, 3), (3, 0)]).unwrap();
+        assert_eq!(
+            PathBasedSCC::on(&graph).collect::<Vec<Component>>(),
+            vec![Component::from(vec![
+                VertexId(0),
+                VertexId(1),
+                VertexId(2),
+                VertexId(3)
+            ])]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    graph::{Graph, VertexId},
+    random::Rng,
+};
+
+/// Generates random walks of a fixed length from every vertex of the graph
+///
+/// The walks are generated one after the other while iterating: In each round one walk starts at each vertex, in the order of the vertices. A walk follows the out edges of the graph and ends early at a vertex without out edges, parallel edges make a neighbour more likely. By default the next vertex is chosen uniformly, with [`RandomWalks::node2vec`] the choice is biased by the previous vertex as in node2vec (see <https://arxiv.org/abs/1607.00653>). The same seed always gives the same walks.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Graph, RandomWalks, VertexId};
+///
+/// // 0 -> 1 -> 2 -> 0 and 2 -> 3
+/// let graph = Graph::from(4, vec![(0, 1), (1, 2), (2, 0), (2, 3)]).unwrap();
+///
+/// let walks = RandomWalks::on(&graph)
+///     .length(5)
+///     .walks_per_vertex(2)
+///     .seed(42)
+///     .collect::<Vec<Vec<VertexId>>>();
+///
+/// assert_eq!(walks.len(), 8);
+/// assert_eq!(walks[0][..3], [VertexId(0), VertexId(1), VertexId(2)]);
+/// assert_eq!(walks[3], vec![VertexId(3)]);
+/// ```
+pub struct RandomWalks<'a> {
+    graph: &'a Graph,
+    rng: Rng,
+    length: usize,
+    walks_per_vertex: usize,
+    // weights of going back to the previous vertex, of staying at its distance and of moving away
+    bias: Option<(f64, f64, f64)>,
+    round: usize,
+    vertices: Vec<&'a VertexId>,
+    next_start: usize,
+}
+impl<'a> RandomWalks<'a> {
+    /// Creates uniform walks with 80 vertices, 10 per vertex, seeded with 0
+    pub fn on(graph: &'a Graph) -> Self {
+        Self {
+            graph,
+            rng: Rng::seed_from(0),
+            length: 80,
+            walks_per_vertex: 10,
+            bias: None,
+            round: 0,
+            vertices: graph.vertices().collect(),
+            next_start: 0,
+        }
+    }
+    /// Number of vertices in each walk, including the start vertex
+    pub fn length(self, length: usize) -> Self {
+        Self { length, ..self }
+    }
+    pub fn walks_per_vertex(self, walks: usize) -> Self {
+        Self {
+            walks_per_vertex: walks,
+            ..self
+        }
+    }
+    pub fn seed(self, seed: u64) -> Self {
+        Self {
+            rng: Rng::seed_from(seed),
+            ..self
+        }
+    }
+    /// Biases the walks with the return parameter `p` and the in-out parameter `q` of node2vec
+    ///
+    /// Coming from vertex t to vertex v, the next vertex x is chosen with a weight of 1/p if x is t, of 1 if there is an edge from t to x and of 1/q otherwise: A small `p` keeps the walk local, a small `q` drives it outwards like a depth first search. With p = q = 1 the walks are uniform. The next vertex is found by rejection sampling, such that each step only needs edge lookups, which are faster on a graph with sorted adjacency (see [`Graph::with_sorted_adjacency`]).
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `p` or `q` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Graph, RandomWalks, VertexId};
+    ///
+    /// let graph = Graph::from(3, vec![(0, 1), (1, 0), (1, 2), (2, 1)]).unwrap();
+    /// let returning = RandomWalks::on(&graph)
+    ///     .length(3)
+    ///     .walks_per_vertex(100)
+    ///     .node2vec(0.01, 100.0)
+    ///     .filter(|walk| walk[0] == VertexId(0));
+    ///
+    /// assert!(returning.filter(|walk| walk[2] == VertexId(0)).count() > 90);
+    /// ```
+    pub fn node2vec(self, p: f64, q: f64) -> Self {
+        assert!(p > 0.0 && q > 0.0, "p and q have to be positive");
+        Self {
+            bias: Some((1.0 / p, 1.0, 1.0 / q)),
+            ..self
+        }
+    }
+
+    fn step(&mut self, previous: Option<&VertexId>, current: &VertexId) -> Option<VertexId> {
+        let neighbors = self.graph.out_neighbor_slice(current);
+        if neighbors.is_empty() {
+            return None;
+        }
+        let (previous, (back, stay, away)) = match (previous, self.bias) {
+            (Some(previous), Some(bias)) => (previous, bias),
+            _ => return Some(neighbors[self.rng.below(neighbors.len())].clone()),
+        };
+        let highest = back.max(stay).max(away);
+        loop {
+            let candidate = &neighbors[self.rng.below(neighbors.len())];
+            let weight = if candidate == previous {
+                back
+            } else if self.graph.has_edge(previous, candidate) {
+                stay
+            } else {
+                away
+            };
+            if self.rng.next_f64() * highest < weight {
+                return Some(candidate.clone());
+            }
+        }
+    }
+    fn walk(&mut self, start: VertexId) -> Vec<VertexId> {
+        let mut walk = Vec::with_capacity(self.length);
+        let mut current = Some(start);
+        while let Some(vertex) = current.take().filter(|_| walk.len() < self.length) {
+            current = self.step(walk.last(), &vertex);
+            walk.push(vertex);
+        }
+        walk
+    }
+}
+
+impl<'a> Iterator for RandomWalks<'a> {
+    type Item = Vec<VertexId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_start == self.vertices.len() {
+            self.next_start = 0;
+            self.round += 1;
+        }
+        if self.round >= self.walks_per_vertex || self.vertices.is_empty() {
+            return None;
+        }
+        let start = self.vertices[self.next_start].clone();
+        self.next_start += 1;
+        Some(self.walk(start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_walk(graph: &Graph, walk: &[VertexId]) -> bool {
+        walk.windows(2)
+            .all(|pair| graph.has_edge(&pair[0], &pair[1]))
+    }
+
+    #[test]
+    fn gives_no_walks_on_empty_graph() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        assert_eq!(RandomWalks::on(&graph).next(), None);
+    }
+
+    #[test]
+    fn gives_walks_of_given_length_from_every_vertex() {
+        let graph = Graph::from(3, vec![(0, 1), (1, 2), (2, 0), (0, 2)]).unwrap();
+        let walks = RandomWalks::on(&graph)
+            .length(6)
+            .walks_per_vertex(3)
+            .collect::<Vec<Vec<VertexId>>>();
+        assert_eq!(walks.len(), 9);
+        for (index, walk) in walks.iter().enumerate() {
+            assert_eq!(walk.len(), 6);
+            assert_eq!(walk[0], VertexId(index % 3));
+            assert!(is_walk(&graph, walk));
+        }
+    }
+
+    #[test]
+    fn ends_walk_at_vertex_without_out_edges() {
+        let graph = Graph::from(3, vec![(0, 1), (1, 2)]).unwrap();
+        assert_eq!(
+            RandomWalks::on(&graph)
+                .length(5)
+                .walks_per_vertex(1)
+                .collect::<Vec<Vec<VertexId>>>(),
+            vec![
+                vec![VertexId(0), VertexId(1), VertexId(2)],
+                vec![VertexId(1), VertexId(2)],
+                vec![VertexId(2)]
+            ]
+        );
+    }
+
+    #[test]
+    fn same_seed_gives_same_walks() {
+        let graph = Graph::from(
+            4,
+            (0..4)
+                .flat_map(|v| [(v, (v + 1) % 4), (v, (v + 2) % 4)])
+                .collect(),
+        )
+        .unwrap();
+        let walks = |seed| {
+            RandomWalks::on(&graph)
+                .length(10)
+                .walks_per_vertex(2)
+                .node2vec(0.5, 2.0)
+                .seed(seed)
+                .collect::<Vec<Vec<VertexId>>>()
+        };
+        assert_eq!(walks(5), walks(5));
+        assert_ne!(walks(5), walks(6));
+        assert!(walks(5).iter().all(|walk| is_walk(&graph, walk)));
+    }
+
+    #[test]
+    fn in_out_parameter_steers_walks_outwards_or_inwards() {
+        // triangle 0 - 1 - 2 with the path 1 - 3 - 4 leading away from 0
+        let graph = Graph::from(
+            5,
+            vec![
+                (0, 1),
+                (1, 0),
+                (1, 2),
+                (2, 1),
+                (0, 2),
+                (2, 0),
+                (1, 3),
+                (3, 1),
+                (3, 4),
+                (4, 3),
+            ],
+        )
+        .unwrap();
+        let moving_away = |q| {
+            RandomWalks::on(&graph)
+                .length(3)
+                .walks_per_vertex(200)
+                .node2vec(1.0, q)
+                .filter(|walk| walk[..2] == [VertexId(0), VertexId(1)])
+                .filter(|walk| walk[2] == VertexId(3))
+                .count()
+        };
+        assert!(moving_away(0.1) > 5 * moving_away(10.0));
+    }
+}
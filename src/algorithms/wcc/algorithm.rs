@@ -11,7 +11,7 @@ impl<'a> WCC<'a> {
     pub fn on(graph: &'a Graph) -> Self {
         Self {
             graph,
-            union_find: UnionFind::new(graph.vertices().map(|v| v.clone())),
+            union_find: UnionFind::new(graph.vertices().cloned()),
         }
     }
     pub fn get(self) -> impl Iterator<Item = Component> {
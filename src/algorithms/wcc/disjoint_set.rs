@@ -0,0 +1,353 @@
+//! Array backed disjoint-set data structures
+//!
+//! The vertex ids are used as positions in arrays, such that a set of the vertices `0..n` needs no hashing. [`DisjointSet`] halves the paths while finding the root of a set and unites by size, which makes each operation take almost constant amortized time. [`RollbackDisjointSet`] does not change paths while finding, such that unions can be undone in the reverse order, e.g. for offline dynamic connectivity.
+
+use crate::graph::VertexId;
+
+/// Disjoint sets of vertices with path halving and union by size
+///
+/// Each vertex starts in a set of its own. Finding the representative of a set and uniting two sets are iterative, also on long chains.
+///
+/// # Panics
+///
+/// All methods that take a vertex will panic if the vertex was not added.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{DisjointSet, VertexId};
+///
+/// let mut sets = DisjointSet::new(4);
+/// assert!(sets.union(&VertexId(0), &VertexId(1)));
+/// assert!(sets.union(&VertexId(3), &VertexId(1)));
+/// assert!(!sets.union(&VertexId(0), &VertexId(3)));
+///
+/// assert!(sets.same_set(&VertexId(0), &VertexId(3)));
+/// assert!(!sets.same_set(&VertexId(0), &VertexId(2)));
+/// assert_eq!(sets.set_size(&VertexId(1)), 3);
+/// assert_eq!(sets.num_sets(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    // only up to date for the representatives
+    size: Vec<usize>,
+    sets: usize,
+}
+impl DisjointSet {
+    /// Creates a set for each of the vertices `0..vertex_count`
+    pub fn new(vertex_count: usize) -> Self {
+        Self {
+            parent: (0..vertex_count).collect(),
+            size: vec![1; vertex_count],
+            sets: vertex_count,
+        }
+    }
+    /// Adds the next vertex in a set of its own and gives it
+    pub fn add(&mut self) -> VertexId {
+        let vertex = self.parent.len();
+        self.parent.push(vertex);
+        self.size.push(1);
+        self.sets += 1;
+        VertexId(vertex)
+    }
+    /// Number of vertices in all sets
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+    /// Gives the representative of the set of the vertex, which is the same for all vertices of the set until the set is united with another one
+    pub fn find(&mut self, vertex: &VertexId) -> VertexId {
+        VertexId(self.root(vertex.0))
+    }
+    /// Unites the sets of both vertices, gives false if they were already in the same set
+    pub fn union(&mut self, first: &VertexId, second: &VertexId) -> bool {
+        let (mut first, mut second) = (self.root(first.0), self.root(second.0));
+        if first == second {
+            return false;
+        }
+        if self.size[first] < self.size[second] {
+            std::mem::swap(&mut first, &mut second);
+        }
+        self.parent[second] = first;
+        self.size[first] += self.size[second];
+        self.sets -= 1;
+        true
+    }
+    pub fn same_set(&mut self, first: &VertexId, second: &VertexId) -> bool {
+        self.root(first.0) == self.root(second.0)
+    }
+    /// Number of vertices in the set of the vertex
+    pub fn set_size(&mut self, vertex: &VertexId) -> usize {
+        let root = self.root(vertex.0);
+        self.size[root]
+    }
+    /// Number of disjoint sets
+    pub fn num_sets(&self) -> usize {
+        self.sets
+    }
+
+    // Lets every other vertex on the path point to its grandparent
+    fn root(&mut self, mut vertex: usize) -> usize {
+        while self.parent[vertex] != vertex {
+            let grandparent = self.parent[self.parent[vertex]];
+            self.parent[vertex] = grandparent;
+            vertex = grandparent;
+        }
+        vertex
+    }
+}
+
+/// Disjoint sets of vertices whose unions can be undone
+///
+/// Uniting by size keeps the trees logarithmically deep without path compression, such that finding takes logarithmic time and does not change the sets. A [`RollbackDisjointSet::snapshot`] marks the current sets, [`RollbackDisjointSet::rollback`] undoes all unions since then in constant time per union.
+///
+/// # Panics
+///
+/// All methods that take a vertex will panic if the vertex was not added.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{RollbackDisjointSet, VertexId};
+///
+/// let mut sets = RollbackDisjointSet::new(3);
+/// sets.union(&VertexId(0), &VertexId(1));
+/// let snapshot = sets.snapshot();
+/// sets.union(&VertexId(1), &VertexId(2));
+/// assert_eq!(sets.num_sets(), 1);
+///
+/// sets.rollback(snapshot);
+/// assert_eq!(sets.num_sets(), 2);
+/// assert!(sets.same_set(&VertexId(0), &VertexId(1)));
+/// assert!(!sets.same_set(&VertexId(1), &VertexId(2)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollbackDisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    sets: usize,
+    // the representatives that were attached to another one, the latest last
+    history: Vec<usize>,
+}
+/// Marks the sets at some point, see [`RollbackDisjointSet::snapshot`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot(usize);
+impl RollbackDisjointSet {
+    /// Creates a set for each of the vertices `0..vertex_count`
+    pub fn new(vertex_count: usize) -> Self {
+        Self {
+            parent: (0..vertex_count).collect(),
+            size: vec![1; vertex_count],
+            sets: vertex_count,
+            history: Vec::new(),
+        }
+    }
+    /// Adds the next vertex in a set of its own and gives it, a rollback does not remove it again
+    pub fn add(&mut self) -> VertexId {
+        let vertex = self.parent.len();
+        self.parent.push(vertex);
+        self.size.push(1);
+        self.sets += 1;
+        VertexId(vertex)
+    }
+    /// Number of vertices in all sets
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+    /// Gives the representative of the set of the vertex
+    pub fn find(&self, vertex: &VertexId) -> VertexId {
+        VertexId(self.root(vertex.0))
+    }
+    /// Unites the sets of both vertices, gives false if they were already in the same set
+    pub fn union(&mut self, first: &VertexId, second: &VertexId) -> bool {
+        let (mut first, mut second) = (self.root(first.0), self.root(second.0));
+        if first == second {
+            return false;
+        }
+        if self.size[first] < self.size[second] {
+            std::mem::swap(&mut first, &mut second);
+        }
+        self.parent[second] = first;
+        self.size[first] += self.size[second];
+        self.sets -= 1;
+        self.history.push(second);
+        true
+    }
+    pub fn same_set(&self, first: &VertexId, second: &VertexId) -> bool {
+        self.root(first.0) == self.root(second.0)
+    }
+    /// Number of vertices in the set of the vertex
+    pub fn set_size(&self, vertex: &VertexId) -> usize {
+        self.size[self.root(vertex.0)]
+    }
+    /// Number of disjoint sets
+    pub fn num_sets(&self) -> usize {
+        self.sets
+    }
+    /// Marks the current sets to return to them with [`RollbackDisjointSet::rollback`]
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.history.len())
+    }
+    /// Undoes the latest union that united two sets, gives false if there is none
+    pub fn undo(&mut self) -> bool {
+        let Some(child) = self.history.pop() else {
+            return false;
+        };
+        let parent = self.parent[child];
+        self.parent[child] = child;
+        self.size[parent] -= self.size[child];
+        self.sets += 1;
+        true
+    }
+    /// Undoes all unions since the snapshot, does nothing if they were already undone
+    pub fn rollback(&mut self, snapshot: Snapshot) {
+        while self.history.len() > snapshot.0 {
+            self.undo();
+        }
+    }
+
+    fn root(&self, mut vertex: usize) -> usize {
+        while self.parent[vertex] != vertex {
+            vertex = self.parent[vertex];
+        }
+        vertex
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+
+    // the smallest vertex in the set of each vertex, by following the united pairs
+    fn smallest_in_set(vertex_count: usize, pairs: &[(usize, usize)]) -> Vec<usize> {
+        let mut smallest = (0..vertex_count).collect::<Vec<usize>>();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &(a, b) in pairs {
+                let min = smallest[a].min(smallest[b]);
+                if smallest[a] != min || smallest[b] != min {
+                    smallest[a] = min;
+                    smallest[b] = min;
+                    changed = true;
+                }
+            }
+        }
+        smallest
+    }
+
+    #[test]
+    fn each_vertex_starts_in_own_set() {
+        let mut sets = DisjointSet::new(3);
+        assert_eq!(sets.num_sets(), 3);
+        assert_eq!(sets.find(&VertexId(2)), VertexId(2));
+        assert_eq!(sets.set_size(&VertexId(1)), 1);
+        assert!(!sets.same_set(&VertexId(0), &VertexId(1)));
+        assert!(DisjointSet::new(0).is_empty());
+    }
+
+    #[test]
+    fn added_vertex_is_in_new_set() {
+        let mut sets = DisjointSet::new(1);
+        assert_eq!(sets.add(), VertexId(1));
+        assert_eq!((sets.len(), sets.num_sets()), (2, 2));
+        assert!(sets.union(&VertexId(1), &VertexId(0)));
+        assert_eq!(sets.set_size(&VertexId(0)), 2);
+        let mut sets = RollbackDisjointSet::new(0);
+        assert_eq!(sets.add(), VertexId(0));
+        assert_eq!(sets.num_sets(), 1);
+    }
+
+    #[test]
+    fn unites_long_chain_without_recursion() {
+        let count = 200_000;
+        let mut sets = DisjointSet::new(count);
+        let mut rollback = RollbackDisjointSet::new(count);
+        for vertex in 1..count {
+            sets.union(&VertexId(vertex), &VertexId(vertex - 1));
+            rollback.union(&VertexId(vertex), &VertexId(vertex - 1));
+        }
+        assert_eq!(sets.set_size(&VertexId(count - 1)), count);
+        assert_eq!(rollback.set_size(&VertexId(count - 1)), count);
+        assert_eq!(sets.num_sets(), 1);
+    }
+
+    #[test]
+    fn agrees_with_united_pairs_on_random_unions() {
+        let mut rng = Rng::seed_from(48);
+        for _ in 0..100 {
+            let count = 1 + rng.below(30);
+            let pairs = (0..rng.below(2 * count))
+                .map(|_| (rng.below(count), rng.below(count)))
+                .collect::<Vec<(usize, usize)>>();
+            let mut sets = DisjointSet::new(count);
+            let mut rollback = RollbackDisjointSet::new(count);
+            for (a, b) in &pairs {
+                assert_eq!(
+                    sets.union(&VertexId(*a), &VertexId(*b)),
+                    rollback.union(&VertexId(*a), &VertexId(*b))
+                );
+            }
+            let smallest = smallest_in_set(count, &pairs);
+            let expected_sets = (0..count).filter(|&v| smallest[v] == v).count();
+            assert_eq!(sets.num_sets(), expected_sets);
+            assert_eq!(rollback.num_sets(), expected_sets);
+            for a in 0..count {
+                let size = smallest.iter().filter(|&&s| s == smallest[a]).count();
+                assert_eq!(sets.set_size(&VertexId(a)), size);
+                assert_eq!(rollback.set_size(&VertexId(a)), size);
+                for b in 0..count {
+                    let same = smallest[a] == smallest[b];
+                    assert_eq!(sets.same_set(&VertexId(a), &VertexId(b)), same);
+                    assert_eq!(rollback.same_set(&VertexId(a), &VertexId(b)), same);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rollback_restores_sets_of_snapshot() {
+        let mut rng = Rng::seed_from(8);
+        for _ in 0..50 {
+            let count = 1 + rng.below(20);
+            let mut sets = RollbackDisjointSet::new(count);
+            let mut states = Vec::new();
+            for _ in 0..rng.below(3 * count) {
+                states.push((sets.snapshot(), sets.clone()));
+                sets.union(&VertexId(rng.below(count)), &VertexId(rng.below(count)));
+            }
+            while let Some((snapshot, state)) = states.pop() {
+                sets.rollback(snapshot);
+                assert_eq!(sets, state);
+            }
+            assert_eq!(sets.num_sets(), count);
+            assert!(!sets.undo());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_sets() {
+        let mut sets = RollbackDisjointSet::new(4);
+        sets.union(&VertexId(0), &VertexId(2));
+        let snapshot = sets.snapshot();
+        sets.union(&VertexId(3), &VertexId(2));
+        let json = serde_json::to_string(&(&sets, snapshot)).unwrap();
+        let (mut restored, snapshot) =
+            serde_json::from_str::<(RollbackDisjointSet, Snapshot)>(&json).unwrap();
+        assert_eq!(restored, sets);
+        restored.rollback(snapshot);
+        assert!(!restored.same_set(&VertexId(3), &VertexId(0)));
+        assert!(restored.same_set(&VertexId(2), &VertexId(0)));
+    }
+}
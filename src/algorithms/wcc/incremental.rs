@@ -0,0 +1,183 @@
+use crate::{
+    algorithms::component::{Component, Partition},
+    graph::{Graph, VertexId},
+};
+
+use super::{disjoint_set::DisjointSet, union_find::Error};
+
+/// Weakly connected components of a growing graph
+///
+/// Keeps the components in a [`DisjointSet`], such that added edges and vertices update them in almost constant amortized time, without running [`super::algorithm::WCC`] on the whole graph again. Only the components are kept, not the edges.
+///
+/// A [`DisjointSet`] is used instead of the [`super::union_find::UnionFind`] of [`super::algorithm::WCC`], because it halves paths and appends new vertices.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{Graph, IncrementalWCC, VertexId};
+///
+/// let graph = Graph::from(3, vec![(0, 1)]).unwrap();
+/// let mut wcc = IncrementalWCC::on(&graph);
+/// assert!(!wcc.connected(&VertexId(0), &VertexId(2)));
+///
+/// let vertex = wcc.add_vertex();
+/// assert_eq!(wcc.add_edge(&VertexId(2), &vertex), Ok(true));
+/// assert_eq!(wcc.add_edge(&vertex, &VertexId(1)), Ok(true));
+/// assert!(wcc.connected(&VertexId(0), &VertexId(2)));
+/// assert_eq!(wcc.num_components(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncrementalWCC {
+    sets: DisjointSet,
+}
+impl IncrementalWCC {
+    /// Starts with the vertices `0..vertex_count` without edges
+    pub fn new(vertex_count: usize) -> Self {
+        Self {
+            sets: DisjointSet::new(vertex_count),
+        }
+    }
+    /// Starts with the vertices and edges of the graph
+    pub fn on(graph: &Graph) -> Self {
+        let mut wcc = Self::new(graph.vertices().count());
+        for edge in graph.edges() {
+            wcc.sets.union(&edge.0, &edge.1);
+        }
+        wcc
+    }
+    /// Adds the next vertex as a component of its own and gives it
+    pub fn add_vertex(&mut self) -> VertexId {
+        self.sets.add()
+    }
+    /// Adds an edge in either direction, gives whether it connected two components
+    pub fn add_edge(&mut self, from: &VertexId, to: &VertexId) -> Result<bool, Error> {
+        self.check(from)?;
+        self.check(to)?;
+        Ok(self.sets.union(from, to))
+    }
+    /// Checks whether both vertices are in the same component, which is false if one of them was not added
+    pub fn connected(&mut self, first: &VertexId, second: &VertexId) -> bool {
+        self.includes(first) && self.includes(second) && self.sets.same_set(first, second)
+    }
+    /// Number of vertices in the component of the vertex
+    pub fn component_size(&mut self, vertex: &VertexId) -> Result<usize, Error> {
+        self.check(vertex)?;
+        Ok(self.sets.set_size(vertex))
+    }
+    /// Gives the vertices of the component of the vertex, this takes linear time in the number of all vertices
+    pub fn component(&mut self, vertex: &VertexId) -> Result<Component, Error> {
+        self.check(vertex)?;
+        let representative = self.sets.find(vertex);
+        Ok(Component::from(
+            (0..self.sets.len())
+                .map(VertexId)
+                .filter(|v| self.sets.find(v) == representative)
+                .collect(),
+        ))
+    }
+    pub fn num_components(&self) -> usize {
+        self.sets.num_sets()
+    }
+    /// Number of added vertices
+    pub fn num_vertices(&self) -> usize {
+        self.sets.len()
+    }
+    /// Gives all current components, ordered by their smallest vertex
+    pub fn components(&mut self) -> Partition {
+        let mut position = vec![None; self.sets.len()];
+        let mut components = Vec::<Component>::new();
+        for vertex in (0..self.sets.len()).map(VertexId) {
+            let representative = self.sets.find(&vertex).0;
+            let index = *position[representative].get_or_insert_with(|| {
+                components.push(Component::new());
+                components.len() - 1
+            });
+            components[index].add(vertex);
+        }
+        components.into_iter().collect()
+    }
+
+    fn includes(&self, vertex: &VertexId) -> bool {
+        vertex.0 < self.sets.len()
+    }
+    fn check(&self, vertex: &VertexId) -> Result<(), Error> {
+        match self.includes(vertex) {
+            true => Ok(()),
+            false => Err(Error::VertexNotIncluded(vertex.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::wcc::algorithm::WCC, random::Rng};
+
+    #[test]
+    fn rejects_vertices_that_were_not_added() {
+        let mut wcc = IncrementalWCC::new(2);
+        assert_eq!(
+            wcc.add_edge(&VertexId(0), &VertexId(2)),
+            Err(Error::VertexNotIncluded(VertexId(2)))
+        );
+        assert_eq!(
+            wcc.component(&VertexId(5)),
+            Err(Error::VertexNotIncluded(VertexId(5)))
+        );
+        assert!(!wcc.connected(&VertexId(2), &VertexId(2)));
+        wcc.add_vertex();
+        assert_eq!(wcc.add_edge(&VertexId(0), &VertexId(2)), Ok(true));
+        assert_eq!(wcc.add_edge(&VertexId(2), &VertexId(0)), Ok(false));
+        assert_eq!(wcc.component_size(&VertexId(2)), Ok(2));
+    }
+
+    #[test]
+    fn gives_components_ordered_by_smallest_vertex() {
+        let graph = Graph::from(5, vec![(4, 1), (2, 0)]).unwrap();
+        let mut wcc = IncrementalWCC::on(&graph);
+        assert_eq!(
+            wcc.components().into_components(),
+            vec![
+                Component::from(vec![VertexId(0), VertexId(2)]),
+                Component::from(vec![VertexId(1), VertexId(4)]),
+                Component::from(vec![VertexId(3)])
+            ]
+        );
+        assert_eq!(
+            wcc.component(&VertexId(4)),
+            Ok(Component::from(vec![VertexId(1), VertexId(4)]))
+        );
+    }
+
+    #[test]
+    fn agrees_with_recomputation_after_each_insertion() {
+        let mut rng = Rng::seed_from(49);
+        for _ in 0..30 {
+            let mut vertex_count = 1 + rng.below(10);
+            let mut edges = Vec::new();
+            let mut wcc = IncrementalWCC::new(vertex_count);
+            for _ in 0..2 * vertex_count {
+                if rng.below(5) == 0 {
+                    assert_eq!(wcc.add_vertex(), VertexId(vertex_count));
+                    vertex_count += 1;
+                } else {
+                    let edge = (rng.below(vertex_count), rng.below(vertex_count));
+                    edges.push(edge);
+                    wcc.add_edge(&VertexId(edge.0), &VertexId(edge.1)).unwrap();
+                }
+                let graph = Graph::from(vertex_count, edges.clone()).unwrap();
+                let mut expected = WCC::on(&graph).get().collect::<Vec<Component>>();
+                expected.sort_by_key(|component| component.sorted()[0].clone());
+                assert_eq!(wcc.components().into_components(), expected);
+                assert_eq!(wcc.num_components(), expected.len());
+                let expected = expected.into_iter().collect::<Partition>();
+                for a in (0..vertex_count).map(VertexId) {
+                    for b in (0..vertex_count).map(VertexId) {
+                        assert_eq!(wcc.connected(&a, &b), expected.same_component(&a, &b));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod algorithm;
+pub mod disjoint_set;
+pub mod incremental;
 pub mod union_find;
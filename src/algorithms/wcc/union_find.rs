@@ -1,16 +1,17 @@
-///! Union-find algorithm to create a disjoint-set data structure
-///!
-///! Creates a forest (undirected acyclic graph) that shows which nodes belong together. The root node of each tree (component) is used as the identify of the tree. The algorithms needs to know all involved nodes upfront. Then it can do two actions:
-///! union two vertices: Add an undirected edge between the two nodes to make them part of the same tree
-///! find a node: Give the identity of the including tree.
-///!
-///! Uses the union by size improvement.
-///! Another improvement that could be implemented: path compression in find fn
+//! Union-find algorithm to create a disjoint-set data structure
+//!
+//! Creates a forest (undirected acyclic graph) that shows which nodes belong together. The root node of each tree (component) is used as the identify of the tree. The algorithms needs to know all involved nodes upfront. Then it can do two actions:
+//! union two vertices: Add an undirected edge between the two nodes to make them part of the same tree
+//! find a node: Give the identity of the including tree.
+//!
+//! Uses the union by size improvement, see [`crate::DisjointSet`] for an array backed variant that also halves paths.
+
 use std::collections::HashMap;
 
 use crate::{algorithms::component::Component, graph::VertexId};
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnionFind {
     list: HashMap<VertexId, Node>,
 }
@@ -26,8 +27,8 @@ impl UnionFind {
             .get(&id)
             .ok_or(Error::VertexNotIncluded(id.clone()))?;
         match x.clone() {
-            Node::TreeRoot(size) => return Ok(ComponentId { id, size }),
-            Node::DecendentOf(id) => return self.find(id),
+            Node::TreeRoot(size) => Ok(ComponentId { id, size }),
+            Node::DecendentOf(id) => self.find(id),
         }
     }
 
@@ -52,17 +53,17 @@ impl UnionFind {
                         self.list.insert(xroot, Node::TreeRoot(xsize + ysize));
                     }
                 }
-                return Ok(());
+                Ok(())
             }
-            (Err(e), _) => return Err(e),
-            (_, Err(e)) => return Err(e),
+            (Err(e), _) => Err(e),
+            (_, Err(e)) => Err(e),
         }
     }
 
     pub fn all_components(&self) -> impl Iterator<Item = Component> {
         self.list
-            .iter()
-            .map(|(v, _)| (v, self.find(v.clone())))
+            .keys()
+            .map(|v| (v, self.find(v.clone())))
             .fold(
                 HashMap::new(),
                 |mut acc: HashMap<VertexId, Component>, (vertex_id, component_id)| {
@@ -72,12 +73,12 @@ impl UnionFind {
                     acc
                 },
             )
-            .into_iter()
-            .map(|(_, component)| component)
+            .into_values()
     }
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Node {
     TreeRoot(usize),
     DecendentOf(VertexId),
@@ -90,6 +91,7 @@ struct ComponentId {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     VertexNotIncluded(VertexId),
 }
@@ -0,0 +1,175 @@
+use crate::graph::{Edge, Graph, VertexId};
+
+/// An immutable dense graph structure for small graphs with many edges
+///
+/// The graph stores its adjacency matrix as bitset: One bit per pair of vertices tells if there is an edge between them. Therefore checking for an edge takes constant time, but the memory grows with the square of the number of vertices. This is useful for graphs with up to a few thousand vertices.
+///
+/// In contrast to [`Graph`], a dense graph has no parallel edges and no weights: Parallel edges of a given graph are merged into a single edge and weights are dropped.
+///
+/// With the `serde` feature a dense graph is serialized like the [`Graph`] of [`DenseGraph::to_graph`], therefore it is checked for dangling edges when it is deserialized.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DenseGraph {
+    vertex_count: usize,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+impl DenseGraph {
+    /// Creates a dense graph with the same vertices and edges as the given graph
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{DenseGraph, Graph, VertexId};
+    ///
+    /// let graph = DenseGraph::from(&Graph::from(3, vec![(0, 2), (0, 2)]).unwrap());
+    ///
+    /// assert!(graph.has_edge(&VertexId(0), &VertexId(2)));
+    /// assert!(!graph.has_edge(&VertexId(2), &VertexId(0)));
+    /// ```
+    pub fn from(graph: &Graph) -> Self {
+        let vertex_count = graph.vertices().count();
+        let words_per_row = vertex_count.div_ceil(64);
+        let mut dense = Self {
+            vertex_count,
+            words_per_row,
+            bits: vec![0; vertex_count * words_per_row],
+        };
+        for Edge(from, to) in graph.edges() {
+            let (word, bit) = dense.position(from, to);
+            dense.bits[word] |= bit;
+        }
+        dense
+    }
+
+    /// Creates a graph with the same vertices and edges
+    ///
+    /// The edges are ordered by their start vertex and then by their end vertex.
+    pub fn to_graph(&self) -> Graph {
+        let edges = (0..self.vertex_count)
+            .flat_map(|from| {
+                self.out_neighbors(VertexId(from))
+                    .map(move |to| (from, to.0))
+            })
+            .collect();
+        Graph::from(self.vertex_count, edges).unwrap()
+    }
+
+    /// Checks if there is an edge from `from` to `to`
+    ///
+    /// Gives false if one of the vertices is not part of the graph.
+    pub fn has_edge(&self, from: &VertexId, to: &VertexId) -> bool {
+        if !self.contains(from) || !self.contains(to) {
+            return false;
+        }
+        let (word, bit) = self.position(from, to);
+        self.bits[word] & bit != 0
+    }
+
+    /// Gives an iterator over all out neighbors for the given `vertex` in ascending order
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vertex` is not part of the graph.
+    pub fn out_neighbors(&self, vertex: VertexId) -> impl Iterator<Item = VertexId> + '_ {
+        if !self.contains(&vertex) {
+            panic!("Vertex {vertex:?} is not part of the graph");
+        }
+        let row = &self.bits[vertex.0 * self.words_per_row..(vertex.0 + 1) * self.words_per_row];
+        row.iter().enumerate().flat_map(|(i, word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| VertexId(64 * i + bit))
+        })
+    }
+
+    pub fn contains(&self, vertex: &VertexId) -> bool {
+        self.vertex_count > vertex.0
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = VertexId> {
+        (0..self.vertex_count).map(VertexId)
+    }
+
+    fn position(&self, from: &VertexId, to: &VertexId) -> (usize, u64) {
+        (from.0 * self.words_per_row + to.0 / 64, 1 << (to.0 % 64))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use super::*;
+
+    impl serde::Serialize for DenseGraph {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.to_graph().serialize(serializer)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for DenseGraph {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Graph::deserialize(deserializer).map(|graph| DenseGraph::from(&graph))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_edges() {
+        let graph = DenseGraph::from(&Graph::from(3, vec![(0, 1), (1, 1), (2, 0)]).unwrap());
+        assert!(graph.has_edge(&VertexId(0), &VertexId(1)));
+        assert!(graph.has_edge(&VertexId(1), &VertexId(1)));
+        assert!(graph.has_edge(&VertexId(2), &VertexId(0)));
+        assert!(!graph.has_edge(&VertexId(1), &VertexId(0)));
+        assert!(!graph.has_edge(&VertexId(0), &VertexId(0)));
+        assert!(!graph.has_edge(&VertexId(0), &VertexId(3)));
+        assert!(!graph.has_edge(&VertexId(3), &VertexId(0)));
+    }
+
+    #[test]
+    fn gets_out_neighbors_across_several_words() {
+        let graph = DenseGraph::from(&Graph::from(130, vec![(1, 129), (1, 0), (1, 64)]).unwrap());
+        assert_eq!(
+            graph.out_neighbors(VertexId(1)).collect::<Vec<VertexId>>(),
+            vec![VertexId(0), VertexId(64), VertexId(129)]
+        );
+        assert_eq!(
+            graph
+                .out_neighbors(VertexId(129))
+                .collect::<Vec<VertexId>>(),
+            vec![]
+        );
+        assert!(graph.has_edge(&VertexId(1), &VertexId(129)));
+        assert!(!graph.has_edge(&VertexId(1), &VertexId(128)));
+    }
+
+    #[test]
+    fn merges_parallel_edges() {
+        let graph = DenseGraph::from(&Graph::from(2, vec![(0, 1), (0, 1), (1, 0)]).unwrap());
+        assert_eq!(
+            graph.to_graph(),
+            Graph::from(2, vec![(0, 1), (1, 0)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn handles_empty_graph() {
+        let graph = DenseGraph::from(&Graph::from(0, vec![]).unwrap());
+        assert_eq!(graph.vertices().count(), 0);
+        assert!(!graph.has_edge(&VertexId(0), &VertexId(0)));
+        assert_eq!(graph.to_graph(), Graph::from(0, vec![]).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_dense_graph() {
+        let graph = DenseGraph::from(&Graph::from(70, vec![(0, 69), (69, 0), (3, 3)]).unwrap());
+        let json = serde_json::to_string(&graph).unwrap();
+        assert_eq!(serde_json::from_str::<DenseGraph>(&json).unwrap(), graph);
+        assert!(
+            serde_json::from_str::<DenseGraph>(r#"{"vertex_count":1,"edges":[[0,1]]}"#).is_err()
+        );
+    }
+}
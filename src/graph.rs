@@ -1,11 +1,11 @@
 /// A unique identifier for a vertex
-
 #[derive(Debug, PartialEq, Clone, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VertexId(pub usize);
 
 /// A directed edge between two vertices
-
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Edge(
     /// from
     pub VertexId,
@@ -15,17 +15,29 @@ pub struct Edge(
 
 /// An immutable graph structure for analytics
 ///
-/// A graph is defined via its vertices and edges. A vertex is uniquely defined via its VertexId. An edge connects two vertices in a specified direction. In this implementation the vertices and edges do not contain any data.
+/// A graph is defined via its vertices and edges. A vertex is uniquely defined via its VertexId. An edge connects two vertices in a specified direction. In this implementation the vertices do not contain any data, edges can optionally carry a weight.
 /// The same vertices can be connected by several edges pointing in the same direction. Dangling edges (edges whos start or end point do not exist) are forbidden and cannot be created with the given implementation. Edges can also have the same start and end vertex.
 /// A graph is immutable, once created it cannot be changed.
-
-/// The graph also includes an out index for faster lookups of out neighbours.
-
-#[derive(Debug, PartialEq)]
+///
+/// The graph also includes an out index for faster lookups of out neighbours. Optionally the out neighbours of each vertex can be sorted (see [`Graph::with_sorted_adjacency`]), which speeds up edge lookups from linear to logarithmic time in the number of out neighbours.
+///
+/// Two graphs are equal if they have the same vertices, edges and weights, the out index and whether it is sorted are not compared.
+///
+/// With the `serde` feature a graph can be serialized, it is given by its vertex count, its edges and its optional weights. Deserialization fails for the same dangling edges that [`Graph::from`] rejects.
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(try_from = "serialization::GraphData")
+)]
 pub struct Graph {
     vertices: Vec<VertexId>,
     edges: Vec<Edge>,
+    weights: Option<Vec<f64>>,
     out_index: Vec<Vec<VertexId>>,
+    // positions in edges, in the same order as out_index
+    out_edges: Vec<Vec<usize>>,
+    sorted_adjacency: bool,
 }
 impl Graph {
     /// Creates a graph with vertex_count vertices and the given edges
@@ -51,30 +63,171 @@ impl Graph {
     ///
     /// assert!(Graph::from(1, vec![(0,1)]).is_err());
     /// ```
-
     pub fn from(vertex_count: usize, edges: Vec<(usize, usize)>) -> Result<Self, String> {
+        Self::create(vertex_count, edges, None)
+    }
+
+    /// Creates a graph with vertex_count vertices and the given weighted edges
+    ///
+    /// Each edge is given as a triple of start vertex, end vertex and weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if one of the given edges is dangling, as in [`Graph::from`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::Graph;
+    ///
+    /// let graph = Graph::from_weighted(2, vec![(0, 1, 0.5)]).unwrap();
+    ///
+    /// assert_eq!(graph.weights(), Some(&[0.5][..]));
+    /// ```
+    pub fn from_weighted(
+        vertex_count: usize,
+        edges: Vec<(usize, usize, f64)>,
+    ) -> Result<Self, String> {
+        let (edges, weights) = edges
+            .into_iter()
+            .map(|(from, to, weight)| ((from, to), weight))
+            .unzip();
+        Self::create(vertex_count, edges, Some(weights))
+    }
+
+    /// Creates a graph from an adjacency matrix
+    ///
+    /// The matrix has one row and one column per vertex. Entry `matrix[i][j]` gives the number of edges from vertex i to vertex j, for a graph without parallel edges it is either 0 or 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::Graph;
+    ///
+    /// assert_eq!(
+    ///     Graph::from_adjacency_matrix(vec![vec![0, 1], vec![0, 1]]).unwrap(),
+    ///     Graph::from(2, vec![(0, 1), (1, 1)]).unwrap()
+    /// );
+    /// ```
+    pub fn from_adjacency_matrix(matrix: Vec<Vec<usize>>) -> Result<Self, String> {
+        Self::check_square(&matrix)?;
+        let edges = matrix
+            .iter()
+            .enumerate()
+            .flat_map(|(from, row)| {
+                row.iter()
+                    .enumerate()
+                    .flat_map(move |(to, count)| std::iter::repeat_n((from, to), *count))
+            })
+            .collect();
+        Self::from(matrix.len(), edges)
+    }
+
+    /// Creates a weighted graph from an adjacency matrix
+    ///
+    /// Entry `matrix[i][j]` is the weight of the edge from vertex i to vertex j or `None` if there is no such edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the matrix is not square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::Graph;
+    ///
+    /// assert_eq!(
+    ///     Graph::from_weighted_adjacency_matrix(vec![vec![None, Some(0.0)], vec![None, None]])
+    ///         .unwrap(),
+    ///     Graph::from_weighted(2, vec![(0, 1, 0.0)]).unwrap()
+    /// );
+    /// ```
+    pub fn from_weighted_adjacency_matrix(matrix: Vec<Vec<Option<f64>>>) -> Result<Self, String> {
+        Self::check_square(&matrix)?;
+        let edges = matrix
+            .iter()
+            .enumerate()
+            .flat_map(|(from, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(move |(to, weight)| weight.map(|weight| (from, to, weight)))
+            })
+            .collect();
+        Self::from_weighted(matrix.len(), edges)
+    }
+
+    fn check_square<T>(matrix: &[Vec<T>]) -> Result<(), String> {
+        match matrix.iter().all(|row| row.len() == matrix.len()) {
+            true => Ok(()),
+            false => Err("Adjacency matrix has to be square".to_string()),
+        }
+    }
+
+    fn create(
+        vertex_count: usize,
+        edges: Vec<(usize, usize)>,
+        weights: Option<Vec<f64>>,
+    ) -> Result<Self, String> {
         let mut out_index: Vec<Vec<VertexId>> = vec![vec![]; vertex_count];
+        let mut out_edges: Vec<Vec<usize>> = vec![vec![]; vertex_count];
         let edges = edges
             .into_iter()
-            .map(|(from, to)| {
+            .enumerate()
+            .map(|(position, (from, to))| {
                 if vertex_count > from && vertex_count > to {
                     let (from, to) = (VertexId(from), VertexId(to));
                     out_index[from.0].push(to.clone());
+                    out_edges[from.0].push(position);
                     Ok(Edge(from, to))
                 } else {
                     Err("Dangling edges are not allowed".to_string())
                 }
             })
             .collect::<Result<Vec<Edge>, String>>();
-        edges.and_then(|edges| {
-            Ok(Self {
-                vertices: (0..vertex_count).map(|i| VertexId(i)).collect(),
-                edges,
-                out_index,
-            })
+        edges.map(|edges| Self {
+            vertices: (0..vertex_count).map(VertexId).collect(),
+            edges,
+            weights,
+            out_index,
+            out_edges,
+            sorted_adjacency: false,
         })
     }
 
+    /// Sorts the out neighbours of each vertex by their id
+    ///
+    /// Afterwards [`Graph::out_neighbors`] gives the neighbours in ascending order and edge lookups like [`Graph::has_edge`] take logarithmic instead of linear time in the number of out neighbours. Parallel edges keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Graph, VertexId};
+    ///
+    /// let graph = Graph::from(3, vec![(0, 2), (0, 1)]).unwrap().with_sorted_adjacency();
+    ///
+    /// let mut out_neighbors = graph.out_neighbors(VertexId(0));
+    ///
+    /// assert_eq!(out_neighbors.next(), Some(VertexId(1)));
+    /// assert_eq!(out_neighbors.next(), Some(VertexId(2)));
+    /// assert_eq!(out_neighbors.next(), None);
+    /// ```
+    pub fn with_sorted_adjacency(mut self) -> Self {
+        for (neighbors, positions) in self.out_index.iter_mut().zip(self.out_edges.iter_mut()) {
+            let mut entries = neighbors
+                .drain(..)
+                .zip(positions.drain(..))
+                .collect::<Vec<(VertexId, usize)>>();
+            entries.sort();
+            (*neighbors, *positions) = entries.into_iter().unzip();
+        }
+        self.sorted_adjacency = true;
+        self
+    }
+
     // TODO returning refs does not make sense because this is a ref to out_index, not vertices
     pub fn out_neighbors_ref<'a>(
         &'a self,
@@ -85,7 +238,7 @@ impl Graph {
 
     /// Gives an iterator over all out neighbors for the given `vertex`
     ///
-    /// Out neighbors of `vertex` are all vertices v for which an edge from the given `vertex` to v exists. The order in which the neighbours are given is not predefined.
+    /// Out neighbors of `vertex` are all vertices v for which an edge from the given `vertex` to v exists. The neighbours are given in the order in which their edges were given to the graph, or in ascending order if the adjacency is sorted (see [`Graph::with_sorted_adjacency`]). Enumerations can visit them in another order, see [`crate::NeighborOrder`].
     ///
     /// # Panics
     ///
@@ -104,13 +257,110 @@ impl Graph {
     /// assert_eq!(out_neighbors.next(), Some(VertexId(0)));
     /// assert_eq!(out_neighbors.next(), None);
     /// ```
-
     pub fn out_neighbors<'a>(&'a self, vertex: VertexId) -> impl Iterator<Item = VertexId> + 'a {
         self.out_index
             .get(vertex.0)
             .unwrap() // TODO get rid of panic
             .iter()
-            .map(|v| v.clone())
+            .cloned()
+    }
+
+    /// Gives an iterator over all out edges for the given `vertex` together with their positions
+    ///
+    /// The edges are given in the same order as [`Graph::out_neighbors`], a position refers to an edge in [`Graph::edges`] and to its weight in [`Graph::weights`]. In contrast to the edge itself the position distinguishes parallel edges.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vertex` is not part of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Edge, Graph, VertexId};
+    ///
+    /// let graph = Graph::from(2, vec![(0, 1), (1, 0), (0, 1)]).unwrap();
+    ///
+    /// let mut out_edges = graph.out_edges(&VertexId(0));
+    ///
+    /// assert_eq!(out_edges.next(), Some((0, &Edge(VertexId(0), VertexId(1)))));
+    /// assert_eq!(out_edges.next(), Some((2, &Edge(VertexId(0), VertexId(1)))));
+    /// assert_eq!(out_edges.next(), None);
+    /// ```
+    pub fn out_edges(&self, vertex: &VertexId) -> impl Iterator<Item = (usize, &Edge)> {
+        self.out_edges
+            .get(vertex.0)
+            .unwrap()
+            .iter()
+            .map(|&position| (position, &self.edges[position]))
+    }
+
+    /// Checks if there is an edge from `from` to `to`
+    ///
+    /// Takes linear time in the number of out neighbours of `from` or logarithmic time if the adjacency is sorted (see [`Graph::with_sorted_adjacency`]). Gives false if one of the vertices is not part of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Graph, VertexId};
+    ///
+    /// let graph = Graph::from(2, vec![(0, 1)]).unwrap();
+    ///
+    /// assert!(graph.has_edge(&VertexId(0), &VertexId(1)));
+    /// assert!(!graph.has_edge(&VertexId(1), &VertexId(0)));
+    /// ```
+    pub fn has_edge(&self, from: &VertexId, to: &VertexId) -> bool {
+        self.find_edges(from, to).next().is_some()
+    }
+
+    /// Gives the number of edges from `from` to `to`
+    ///
+    /// This is larger than one if there are parallel edges. Takes the same time as [`Graph::has_edge`] plus the number of found edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Graph, VertexId};
+    ///
+    /// let graph = Graph::from(2, vec![(0, 1), (1, 0), (0, 1)]).unwrap();
+    ///
+    /// assert_eq!(graph.edge_count(&VertexId(0), &VertexId(1)), 2);
+    /// assert_eq!(graph.edge_count(&VertexId(1), &VertexId(1)), 0);
+    /// ```
+    pub fn edge_count(&self, from: &VertexId, to: &VertexId) -> usize {
+        self.find_edges(from, to).count()
+    }
+
+    /// Gives the positions of all edges from `from` to `to`
+    ///
+    /// A position refers to an edge in [`Graph::edges`] and to its weight in [`Graph::weights`]. Takes the same time as [`Graph::has_edge`] plus the number of found edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{Graph, VertexId};
+    ///
+    /// let graph = Graph::from_weighted(2, vec![(0, 1, 2.0), (1, 0, 1.0), (0, 1, 5.0)]).unwrap();
+    ///
+    /// let weights = graph.weights().unwrap();
+    /// assert_eq!(
+    ///     graph
+    ///         .find_edges(&VertexId(0), &VertexId(1))
+    ///         .map(|position| weights[position])
+    ///         .collect::<Vec<f64>>(),
+    ///     vec![2.0, 5.0]
+    /// );
+    /// ```
+    pub fn find_edges(&self, from: &VertexId, to: &VertexId) -> impl Iterator<Item = usize> + '_ {
+        let neighbors = self.out_index.get(from.0).map_or(&[][..], |n| n.as_slice());
+        let positions = self.out_edges.get(from.0).map_or(&[][..], |p| p.as_slice());
+        let candidates = match self.sorted_adjacency {
+            true => neighbors.partition_point(|v| v < to)..neighbors.partition_point(|v| v <= to),
+            false => 0..neighbors.len(),
+        };
+        let to = to.clone();
+        candidates
+            .filter(move |i| neighbors[*i] == to)
+            .map(move |i| positions[i])
     }
 
     /// Checks if the graph contains a given `vertex`
@@ -126,11 +376,20 @@ impl Graph {
     /// assert!(graph.contains(&VertexId(1)));
     /// assert!(!graph.contains(&VertexId(2)));
     /// ```
-
     pub fn contains(&self, vertex: &VertexId) -> bool {
         self.vertices.len() > vertex.0
     }
 
+    // Gives the id that is owned by the graph
+    pub(crate) fn vertex(&self, vertex: &VertexId) -> Option<&VertexId> {
+        self.vertices.get(vertex.0)
+    }
+
+    // Out neighbours in the same order as out_neighbors, for random access
+    pub(crate) fn out_neighbor_slice(&self, vertex: &VertexId) -> &[VertexId] {
+        self.out_index.get(vertex.0).map_or(&[], |n| n.as_slice())
+    }
+
     /// Gives an iterator over all vertices in the graph
     ///
     /// # Examples
@@ -146,7 +405,6 @@ impl Graph {
     /// assert_eq!(vertices.next(), Some(&VertexId(1)));
     /// assert_eq!(vertices.next(), None);
     /// ```
-
     pub fn vertices(&self) -> impl Iterator<Item = &VertexId> {
         self.vertices.iter()
     }
@@ -154,6 +412,135 @@ impl Graph {
     pub fn edges(&self) -> impl Iterator<Item = &Edge> {
         self.edges.iter()
     }
+
+    /// Gives the edge weights if the graph is weighted
+    ///
+    /// The weights are given in the same order as the edges in [`Graph::edges`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::Graph;
+    ///
+    /// assert_eq!(Graph::from(2, vec![(0, 1)]).unwrap().weights(), None);
+    /// assert_eq!(
+    ///     Graph::from_weighted(2, vec![(0, 1, 2.0), (1, 0, 3.0)]).unwrap().weights(),
+    ///     Some(&[2.0, 3.0][..])
+    /// );
+    /// ```
+    pub fn weights(&self) -> Option<&[f64]> {
+        self.weights.as_deref()
+    }
+
+    /// Gives the adjacency matrix of the graph
+    ///
+    /// Entry `[i][j]` is the number of edges from vertex i to vertex j, see [`Graph::from_adjacency_matrix`]. Weights are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::Graph;
+    ///
+    /// let graph = Graph::from(2, vec![(0, 1), (0, 1), (1, 0)]).unwrap();
+    ///
+    /// assert_eq!(graph.to_adjacency_matrix(), vec![vec![0, 2], vec![1, 0]]);
+    /// ```
+    pub fn to_adjacency_matrix(&self) -> Vec<Vec<usize>> {
+        let mut matrix = vec![vec![0; self.vertices.len()]; self.vertices.len()];
+        for Edge(from, to) in &self.edges {
+            matrix[from.0][to.0] += 1;
+        }
+        matrix
+    }
+
+    /// Gives the weighted adjacency matrix of the graph
+    ///
+    /// Entry `[i][j]` is the weight of the edge from vertex i to vertex j, see [`Graph::from_weighted_adjacency_matrix`]. The edges of an unweighted graph get weight 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the graph has parallel edges, their weights cannot be given in a single entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::Graph;
+    ///
+    /// let graph = Graph::from_weighted(2, vec![(1, 0, 0.5)]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     graph.to_weighted_adjacency_matrix().unwrap(),
+    ///     vec![vec![None, None], vec![Some(0.5), None]]
+    /// );
+    /// ```
+    pub fn to_weighted_adjacency_matrix(&self) -> Result<Vec<Vec<Option<f64>>>, String> {
+        let mut matrix = vec![vec![None; self.vertices.len()]; self.vertices.len()];
+        for (i, Edge(from, to)) in self.edges.iter().enumerate() {
+            let weight = self.weights().map_or(1.0, |weights| weights[i]);
+            if matrix[from.0][to.0].replace(weight).is_some() {
+                return Err("Parallel edges cannot be given in an adjacency matrix".to_string());
+            }
+        }
+        Ok(matrix)
+    }
+}
+
+impl PartialEq for Graph {
+    fn eq(&self, other: &Self) -> bool {
+        self.vertices == other.vertices
+            && self.edges == other.edges
+            && self.weights == other.weights
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use super::*;
+
+    #[derive(serde::Serialize)]
+    struct GraphRef<'a> {
+        vertex_count: usize,
+        edges: &'a [Edge],
+        weights: Option<&'a [f64]>,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct GraphData {
+        vertex_count: usize,
+        edges: Vec<Edge>,
+        #[serde(default)]
+        weights: Option<Vec<f64>>,
+    }
+
+    impl serde::Serialize for Graph {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            GraphRef {
+                vertex_count: self.vertices.len(),
+                edges: &self.edges,
+                weights: self.weights(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl TryFrom<GraphData> for Graph {
+        type Error = String;
+
+        fn try_from(data: GraphData) -> Result<Self, Self::Error> {
+            let edges = data.edges.into_iter().map(|Edge(from, to)| (from.0, to.0));
+            match data.weights {
+                None => Graph::from(data.vertex_count, edges.collect()),
+                Some(weights) if weights.len() == edges.len() => Graph::from_weighted(
+                    data.vertex_count,
+                    edges
+                        .zip(weights)
+                        .map(|((from, to), weight)| (from, to, weight))
+                        .collect(),
+                ),
+                Some(_) => Err("Number of weights has to match number of edges".to_string()),
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -162,31 +549,53 @@ mod tests {
 
     #[test]
     fn creates_graph_from_edge_topology() {
+        let graph = Graph::from(6, vec![(0, 1), (4, 5), (1, 1)]).unwrap();
         assert_eq!(
-            Graph::from(6, vec![(0, 1), (4, 5), (1, 1)]).unwrap(),
-            Graph {
-                vertices: vec![
-                    VertexId(0),
-                    VertexId(1),
-                    VertexId(2),
-                    VertexId(3),
-                    VertexId(4),
-                    VertexId(5)
-                ],
-                edges: vec![
-                    Edge(VertexId(0), VertexId(1)),
-                    Edge(VertexId(4), VertexId(5)),
-                    Edge(VertexId(1), VertexId(1))
-                ],
-                out_index: vec![
-                    vec![VertexId(1)],
-                    vec![VertexId(1)],
-                    vec![],
-                    vec![],
-                    vec![VertexId(5)],
-                    vec![]
-                ]
-            }
+            graph.vertices().cloned().collect::<Vec<VertexId>>(),
+            (0..6).map(VertexId).collect::<Vec<VertexId>>()
+        );
+        assert_eq!(
+            graph.edges().collect::<Vec<&Edge>>(),
+            vec![
+                &Edge(VertexId(0), VertexId(1)),
+                &Edge(VertexId(4), VertexId(5)),
+                &Edge(VertexId(1), VertexId(1))
+            ]
+        );
+        assert_eq!(graph.weights(), None);
+        assert_eq!(
+            graph
+                .vertices()
+                .map(|v| graph.out_neighbors(v.clone()).collect())
+                .collect::<Vec<Vec<VertexId>>>(),
+            vec![
+                vec![VertexId(1)],
+                vec![VertexId(1)],
+                vec![],
+                vec![],
+                vec![VertexId(5)],
+                vec![]
+            ]
+        );
+        assert_eq!(
+            graph
+                .out_edges(&VertexId(4))
+                .collect::<Vec<(usize, &Edge)>>(),
+            vec![(1, &Edge(VertexId(4), VertexId(5)))]
+        );
+    }
+
+    #[test]
+    fn sorting_adjacency_keeps_graph_equal() {
+        let graph = Graph::from(3, vec![(0, 2), (0, 1), (2, 0)]).unwrap();
+        let sorted = Graph::from(3, vec![(0, 2), (0, 1), (2, 0)])
+            .unwrap()
+            .with_sorted_adjacency();
+        assert_eq!(sorted, graph);
+        assert_ne!(Graph::from(3, vec![(0, 1), (0, 2), (2, 0)]).unwrap(), graph);
+        assert_ne!(
+            Graph::from_weighted(3, vec![(0, 2, 1.0), (0, 1, 1.0), (2, 0, 1.0)]).unwrap(),
+            graph
         );
     }
 
@@ -195,6 +604,122 @@ mod tests {
         assert!(Graph::from(0, vec![(0, 0)]).is_err());
     }
 
+    #[test]
+    fn creates_weighted_graph() {
+        let graph = Graph::from_weighted(3, vec![(0, 1, 1.5), (2, 0, -2.0)]).unwrap();
+        assert_eq!(
+            graph.edges().collect::<Vec<&Edge>>(),
+            vec![
+                &Edge(VertexId(0), VertexId(1)),
+                &Edge(VertexId(2), VertexId(0))
+            ]
+        );
+        assert_eq!(graph.weights(), Some(&[1.5, -2.0][..]));
+        assert!(Graph::from_weighted(1, vec![(0, 1, 1.0)]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_graph() {
+        let graph = Graph::from(3, vec![(0, 1), (2, 2)]).unwrap();
+        let json = serde_json::to_string(&graph).unwrap();
+        assert_eq!(
+            json,
+            r#"{"vertex_count":3,"edges":[[0,1],[2,2]],"weights":null}"#
+        );
+        assert_eq!(serde_json::from_str::<Graph>(&json).unwrap(), graph);
+
+        let graph = Graph::from_weighted(2, vec![(0, 1, 0.5)]).unwrap();
+        let json = serde_json::to_string(&graph).unwrap();
+        assert_eq!(serde_json::from_str::<Graph>(&json).unwrap(), graph);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn does_not_deserialize_invalid_graph() {
+        assert!(serde_json::from_str::<Graph>(r#"{"vertex_count":1,"edges":[[0,1]]}"#).is_err());
+        assert!(serde_json::from_str::<Graph>(
+            r#"{"vertex_count":2,"edges":[[0,1]],"weights":[1.0,2.0]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn converts_from_and_to_adjacency_matrix() {
+        let matrix = vec![vec![1, 2, 0], vec![0, 0, 0], vec![1, 0, 0]];
+        let graph = Graph::from_adjacency_matrix(matrix.clone()).unwrap();
+        assert_eq!(
+            graph,
+            Graph::from(3, vec![(0, 0), (0, 1), (0, 1), (2, 0)]).unwrap()
+        );
+        assert_eq!(graph.to_adjacency_matrix(), matrix);
+        assert!(Graph::from_adjacency_matrix(vec![vec![0, 1]]).is_err());
+    }
+
+    #[test]
+    fn converts_from_and_to_weighted_adjacency_matrix() {
+        let matrix = vec![vec![None, Some(2.0)], vec![Some(-1.0), Some(0.0)]];
+        let graph = Graph::from_weighted_adjacency_matrix(matrix.clone()).unwrap();
+        assert_eq!(
+            graph,
+            Graph::from_weighted(2, vec![(0, 1, 2.0), (1, 0, -1.0), (1, 1, 0.0)]).unwrap()
+        );
+        assert_eq!(graph.to_weighted_adjacency_matrix().unwrap(), matrix);
+        assert_eq!(
+            Graph::from(2, vec![(0, 1)])
+                .unwrap()
+                .to_weighted_adjacency_matrix()
+                .unwrap(),
+            vec![vec![None, Some(1.0)], vec![None, None]]
+        );
+        assert!(Graph::from(2, vec![(0, 1), (0, 1)])
+            .unwrap()
+            .to_weighted_adjacency_matrix()
+            .is_err());
+        assert!(Graph::from_weighted_adjacency_matrix(vec![vec![], vec![]]).is_err());
+    }
+
+    #[test]
+    fn finds_edges() {
+        let edges = vec![(0, 2), (0, 1), (1, 0), (0, 2), (0, 0), (2, 2)];
+        for graph in [
+            Graph::from(3, edges.clone()).unwrap(),
+            Graph::from(3, edges).unwrap().with_sorted_adjacency(),
+        ] {
+            assert_eq!(
+                graph
+                    .find_edges(&VertexId(0), &VertexId(2))
+                    .collect::<Vec<usize>>(),
+                vec![0, 3]
+            );
+            assert!(graph.has_edge(&VertexId(0), &VertexId(0)));
+            assert!(graph.has_edge(&VertexId(2), &VertexId(2)));
+            assert!(!graph.has_edge(&VertexId(2), &VertexId(0)));
+            assert!(!graph.has_edge(&VertexId(3), &VertexId(0)));
+            assert!(!graph.has_edge(&VertexId(0), &VertexId(3)));
+            assert_eq!(graph.edge_count(&VertexId(0), &VertexId(2)), 2);
+            assert_eq!(graph.edge_count(&VertexId(1), &VertexId(0)), 1);
+            assert_eq!(graph.edge_count(&VertexId(1), &VertexId(1)), 0);
+        }
+    }
+
+    #[test]
+    fn sorts_adjacency() {
+        let graph = Graph::from(4, vec![(0, 3), (0, 1), (0, 2), (0, 1)])
+            .unwrap()
+            .with_sorted_adjacency();
+        assert_eq!(
+            graph.out_neighbors(VertexId(0)).collect::<Vec<VertexId>>(),
+            vec![VertexId(1), VertexId(1), VertexId(2), VertexId(3)]
+        );
+        assert_eq!(
+            graph
+                .find_edges(&VertexId(0), &VertexId(1))
+                .collect::<Vec<usize>>(),
+            vec![1, 3]
+        );
+    }
+
     #[test]
     fn gets_out_neighbors() {
         let graph = Graph::from(5, vec![(0, 0), (0, 1), (0, 1), (0, 2), (1, 4)]).unwrap();
@@ -0,0 +1,190 @@
+//! Adjacency matrix as text
+//!
+//! Each line contains one row of the matrix with its entries separated by whitespace, empty lines are ignored. In an unweighted matrix entry j of row i is the number of edges from vertex i to vertex j (see [`Graph::from_adjacency_matrix`]). In a weighted matrix the entry is the weight of the edge or `-` if there is no such edge (see [`Graph::from_weighted_adjacency_matrix`]).
+use std::io::{BufRead, Write};
+
+use super::{parse_number, Error};
+use crate::graph::Graph;
+
+/// Reads a graph from an unweighted adjacency matrix
+///
+/// # Errors
+///
+/// Returns `Err` if an entry is not a non-negative integer or if the matrix is not square.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::adjacency_matrix, Graph};
+///
+/// let content = "0 1
+///                0 0";
+///
+/// assert_eq!(
+///     adjacency_matrix::read(content.as_bytes()).unwrap(),
+///     Graph::from(2, vec![(0, 1)]).unwrap()
+/// );
+/// ```
+pub fn read(reader: impl BufRead) -> Result<Graph, Error> {
+    let matrix = read_rows(reader, |token, line| {
+        parse_number(Some(token), line, "edge count")
+    })?;
+    Graph::from_adjacency_matrix(matrix).map_err(Error::InvalidGraph)
+}
+
+/// Reads a graph from a weighted adjacency matrix
+///
+/// # Errors
+///
+/// Returns `Err` if an entry is neither a number nor `-` or if the matrix is not square.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::adjacency_matrix, Graph};
+///
+/// let content = "-   0.5
+///                1.5 -";
+///
+/// assert_eq!(
+///     adjacency_matrix::read_weighted(content.as_bytes()).unwrap(),
+///     Graph::from_weighted(2, vec![(0, 1, 0.5), (1, 0, 1.5)]).unwrap()
+/// );
+/// ```
+pub fn read_weighted(reader: impl BufRead) -> Result<Graph, Error> {
+    let matrix = read_rows(reader, |token, line| match token {
+        "-" => Ok(None),
+        _ => parse_number(Some(token), line, "weight").map(Some),
+    })?;
+    Graph::from_weighted_adjacency_matrix(matrix).map_err(Error::InvalidGraph)
+}
+
+fn read_rows<T>(
+    reader: impl BufRead,
+    parse: impl Fn(&str, usize) -> Result<T, Error>,
+) -> Result<Vec<Vec<T>>, Error> {
+    let mut matrix = Vec::new();
+    for (number, line) in reader.lines().enumerate().map(|(i, l)| (i + 1, l)) {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        matrix.push(
+            line.split_whitespace()
+                .map(|token| parse(token, number))
+                .collect::<Result<Vec<T>, Error>>()?,
+        );
+    }
+    Ok(matrix)
+}
+
+/// Writes the unweighted adjacency matrix of a graph
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::adjacency_matrix, Graph};
+///
+/// let mut content = Vec::new();
+/// adjacency_matrix::write(&Graph::from(2, vec![(1, 0)]).unwrap(), &mut content).unwrap();
+///
+/// assert_eq!(String::from_utf8(content).unwrap(), "0 0\n1 0\n");
+/// ```
+pub fn write(graph: &Graph, writer: impl Write) -> Result<(), Error> {
+    write_rows(graph.to_adjacency_matrix(), writer, |count| {
+        count.to_string()
+    })
+}
+
+/// Writes the weighted adjacency matrix of a graph
+///
+/// # Errors
+///
+/// Returns `Err` if the graph has parallel edges, see [`Graph::to_weighted_adjacency_matrix`].
+pub fn write_weighted(graph: &Graph, writer: impl Write) -> Result<(), Error> {
+    let matrix = graph
+        .to_weighted_adjacency_matrix()
+        .map_err(Error::InvalidGraph)?;
+    write_rows(matrix, writer, |weight| match weight {
+        Some(weight) => weight.to_string(),
+        None => "-".to_string(),
+    })
+}
+
+fn write_rows<T>(
+    matrix: Vec<Vec<T>>,
+    mut writer: impl Write,
+    format: impl Fn(&T) -> String,
+) -> Result<(), Error> {
+    for row in matrix {
+        writeln!(
+            writer,
+            "{}",
+            row.iter().map(&format).collect::<Vec<String>>().join(" ")
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_unweighted_matrix() {
+        let content = "0 2 0\n\n0 0 0\n1 0 1\n";
+        assert_eq!(
+            read(content.as_bytes()).unwrap(),
+            Graph::from(3, vec![(0, 1), (0, 1), (2, 0), (2, 2)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn reads_weighted_matrix() {
+        let content = "0 -\n- -2.5\n";
+        assert_eq!(
+            read_weighted(content.as_bytes()).unwrap(),
+            Graph::from_weighted(2, vec![(0, 0, 0.0), (1, 1, -2.5)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_matrix() {
+        assert!(matches!(
+            read("0 1\n0 x\n".as_bytes()),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            read("0 -1\n0 0\n".as_bytes()),
+            Err(Error::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            read("0 1\n0\n".as_bytes()),
+            Err(Error::InvalidGraph(_))
+        ));
+        assert!(matches!(
+            read_weighted("- x\n- -\n".as_bytes()),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn written_matrix_can_be_read_again() {
+        let graph = Graph::from(3, vec![(0, 1), (0, 1), (2, 2)]).unwrap();
+        let mut content = Vec::new();
+        write(&graph, &mut content).unwrap();
+        assert_eq!(read(content.as_slice()).unwrap(), graph);
+
+        let graph = Graph::from_weighted(2, vec![(0, 1, 0.25), (1, 1, 3.0)]).unwrap();
+        let mut content = Vec::new();
+        write_weighted(&graph, &mut content).unwrap();
+        assert_eq!(String::from_utf8(content.clone()).unwrap(), "- 0.25\n- 3\n");
+        assert_eq!(read_weighted(content.as_slice()).unwrap(), graph);
+
+        let graph = Graph::from_weighted(2, vec![(0, 1, 0.25), (0, 1, 3.0)]).unwrap();
+        assert!(matches!(
+            write_weighted(&graph, Vec::new()),
+            Err(Error::InvalidGraph(_))
+        ));
+    }
+}
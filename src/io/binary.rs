@@ -0,0 +1,480 @@
+//! Compact binary format that can be used without parsing
+//!
+//! The format stores a graph in compressed sparse row (CSR) form, all numbers are little endian:
+//!
+//! | content    | type              | description                                                  |
+//! |------------|-------------------|--------------------------------------------------------------|
+//! | magic      | 8 bytes           | `GRAPHBIN`                                                   |
+//! | version    | u32               | version of the format, currently 1                           |
+//! | flags      | u32               | bit 0 is set if the graph is weighted                        |
+//! | vertices   | u64               | number of vertices n                                         |
+//! | edges      | u64               | number of edges m                                            |
+//! | offsets    | (n + 1) x u64     | out edges of vertex i are at positions `offsets[i]..offsets[i + 1]` |
+//! | targets    | m x u64           | end vertex of each edge                                      |
+//! | weights    | m x f64           | weight of each edge, only present for weighted graphs        |
+//! | checksum   | u64               | FNV-1a hash of all previous bytes                            |
+//!
+//! A [`BinaryGraph`] reads the graph directly from these bytes. Opening it only checks the header and the file size, therefore it takes constant time. Together with a memory mapped file (see [`open`]) a huge graph is available immediately and can be shared read-only between processes.
+//!
+//! Only opening and the queries of a [`BinaryGraph`] work on the bytes directly. The algorithms of this crate take a [`Graph`], which [`BinaryGraph::to_graph`] creates in linear time O(V + E).
+use std::io::Write;
+
+use super::Error;
+use crate::graph::{Edge, Graph, VertexId};
+
+const MAGIC: &[u8; 8] = b"GRAPHBIN";
+const VERSION: u32 = 1;
+const WEIGHTED: u32 = 1;
+const HEADER_SIZE: usize = 32;
+const CHECKSUM_SIZE: usize = 8;
+
+/// Writes a graph in the binary format
+///
+/// The edges are stored grouped by their start vertex, the out edges of each vertex in the order of [`Graph::out_edges`], which is sorted if the graph has a sorted adjacency.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::binary::{self, BinaryGraph}, Graph, VertexId};
+///
+/// let mut bytes = Vec::new();
+/// binary::write(&Graph::from(3, vec![(0, 1), (0, 2)]).unwrap(), &mut bytes).unwrap();
+///
+/// let graph = BinaryGraph::from_bytes(bytes).unwrap();
+/// assert_eq!(
+///     graph.out_neighbors(VertexId(0)).collect::<Vec<VertexId>>(),
+///     vec![VertexId(1), VertexId(2)]
+/// );
+/// ```
+pub fn write(graph: &Graph, writer: impl Write) -> Result<(), Error> {
+    let mut writer = ChecksumWriter::from(writer);
+
+    let vertex_count = graph.vertices().count();
+    let edges = graph
+        .vertices()
+        .flat_map(|vertex| graph.out_edges(vertex))
+        .collect::<Vec<_>>();
+
+    writer.write(MAGIC)?;
+    writer.write(&VERSION.to_le_bytes())?;
+    let flags = match graph.weights() {
+        Some(_) => WEIGHTED,
+        None => 0,
+    };
+    writer.write(&flags.to_le_bytes())?;
+    writer.write(&(vertex_count as u64).to_le_bytes())?;
+    writer.write(&(edges.len() as u64).to_le_bytes())?;
+
+    let mut offset = 0u64;
+    writer.write(&offset.to_le_bytes())?;
+    for vertex in graph.vertices() {
+        offset += graph.out_edges(vertex).count() as u64;
+        writer.write(&offset.to_le_bytes())?;
+    }
+    for (_, edge) in &edges {
+        writer.write(&(edge.1 .0 as u64).to_le_bytes())?;
+    }
+    if let Some(weights) = graph.weights() {
+        for (i, _) in &edges {
+            writer.write(&weights[*i].to_le_bytes())?;
+        }
+    }
+
+    let checksum = writer.checksum.0;
+    writer.write(&checksum.to_le_bytes())?;
+    Ok(())
+}
+
+/// A read-only graph that directly works on bytes in the binary format
+///
+/// The bytes can be anything that can be referenced as a byte slice, e.g. a `Vec<u8>` or a memory mapped file.
+pub struct BinaryGraph<B> {
+    bytes: B,
+    vertex_count: usize,
+    edge_count: usize,
+    weighted: bool,
+}
+impl<B> BinaryGraph<B>
+where
+    B: AsRef<[u8]>,
+{
+    /// Creates a graph on the given bytes
+    ///
+    /// Only the header and the size of the bytes are checked, the checksum is not verified (see [`BinaryGraph::verify`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the bytes do not start with a valid header of a supported version or if their size does not fit the header.
+    pub fn from_bytes(bytes: B) -> Result<Self, Error> {
+        let content = bytes.as_ref();
+        if content.len() < HEADER_SIZE + CHECKSUM_SIZE || &content[0..8] != MAGIC {
+            return Err(Error::Corrupted("not a binary graph".to_string()));
+        }
+        let version = read_u32(content, 8);
+        if version != VERSION {
+            return Err(Error::Unsupported(format!(
+                "binary format version {version}"
+            )));
+        }
+        let weighted = read_u32(content, 12) & WEIGHTED != 0;
+        let vertex_count = read_u64(content, 16) as usize;
+        let edge_count = read_u64(content, 24) as usize;
+
+        let graph = Self {
+            bytes,
+            vertex_count,
+            edge_count,
+            weighted,
+        };
+        if graph.size() != Some(graph.bytes.as_ref().len()) {
+            return Err(Error::Corrupted("size does not fit the header".to_string()));
+        }
+        Ok(graph)
+    }
+
+    /// Checks the checksum of the bytes
+    ///
+    /// Takes linear time in the size of the graph.
+    pub fn verify(&self) -> Result<(), Error> {
+        let content = self.bytes.as_ref();
+        let checksum_position = content.len() - CHECKSUM_SIZE;
+        let mut checksum = Checksum::new();
+        checksum.update(&content[..checksum_position]);
+        if checksum.0 != read_u64(content, checksum_position) {
+            return Err(Error::Corrupted("checksum does not match".to_string()));
+        }
+        Ok(())
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    pub fn contains(&self, vertex: &VertexId) -> bool {
+        self.vertex_count > vertex.0
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = VertexId> {
+        (0..self.vertex_count).map(VertexId)
+    }
+
+    /// Gives an iterator over all edges, grouped by their start vertex like in [`BinaryGraph::out_neighbors`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graph::{io::binary::{self, BinaryGraph}, Edge, Graph, VertexId};
+    ///
+    /// let mut bytes = Vec::new();
+    /// binary::write(&Graph::from(3, vec![(2, 0), (0, 1)]).unwrap(), &mut bytes).unwrap();
+    ///
+    /// let graph = BinaryGraph::from_bytes(bytes).unwrap();
+    /// assert_eq!(graph.edge_count(), 2);
+    /// assert_eq!(
+    ///     graph.edges().collect::<Vec<Edge>>(),
+    ///     vec![Edge(VertexId(0), VertexId(1)), Edge(VertexId(2), VertexId(0))]
+    /// );
+    /// ```
+    pub fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        self.vertices().flat_map(move |from| {
+            self.out_neighbors(from.clone())
+                .map(move |to| Edge(from.clone(), to))
+        })
+    }
+
+    /// Number of out edges of the given `vertex`, which takes constant time
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vertex` is not part of the graph.
+    pub fn out_degree(&self, vertex: &VertexId) -> usize {
+        self.out_edge_positions(vertex).len()
+    }
+
+    pub fn is_weighted(&self) -> bool {
+        self.weighted
+    }
+
+    /// Gives an iterator over all out neighbors for the given `vertex`
+    ///
+    /// The neighbors are given in the same order as [`Graph::out_neighbors`] of the written graph.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vertex` is not part of the graph.
+    pub fn out_neighbors(&self, vertex: VertexId) -> impl Iterator<Item = VertexId> + '_ {
+        self.out_edge_positions(&vertex).map(|i| self.target(i))
+    }
+
+    /// Gives an iterator over the weights of all out edges of the given `vertex`
+    ///
+    /// The weights are in the same order as the neighbors in [`BinaryGraph::out_neighbors`]. Gives `None` if the graph is not weighted.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `vertex` is not part of the graph.
+    pub fn out_weights(&self, vertex: VertexId) -> Option<impl Iterator<Item = f64> + '_> {
+        let positions = self.out_edge_positions(&vertex);
+        self.weighted.then(|| positions.map(|i| self.weight(i)))
+    }
+
+    /// Creates a [`Graph`] with the content of the bytes
+    ///
+    /// Takes linear time in the size of the graph, because all edges are read and copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the bytes contain a dangling edge.
+    pub fn to_graph(&self) -> Result<Graph, Error> {
+        let edges = (0..self.vertex_count).flat_map(|from| {
+            self.out_edge_positions(&VertexId(from))
+                .map(move |i| (from, self.target(i).0, i))
+        });
+        match self.weighted {
+            false => Graph::from(
+                self.vertex_count,
+                edges.map(|(from, to, _)| (from, to)).collect(),
+            ),
+            true => Graph::from_weighted(
+                self.vertex_count,
+                edges
+                    .map(|(from, to, i)| (from, to, self.weight(i)))
+                    .collect(),
+            ),
+        }
+        .map_err(Error::InvalidGraph)
+    }
+
+    fn out_edge_positions(&self, vertex: &VertexId) -> std::ops::Range<usize> {
+        if vertex.0 >= self.vertex_count {
+            panic!("Vertex {vertex:?} is not part of the graph");
+        }
+        let content = self.bytes.as_ref();
+        let begin = read_u64(content, HEADER_SIZE + 8 * vertex.0) as usize;
+        let end = read_u64(content, HEADER_SIZE + 8 * (vertex.0 + 1)) as usize;
+        begin.min(self.edge_count)..end.min(self.edge_count)
+    }
+    fn target(&self, position: usize) -> VertexId {
+        VertexId(read_u64(self.bytes.as_ref(), self.targets() + 8 * position) as usize)
+    }
+    fn weight(&self, position: usize) -> f64 {
+        f64::from_le_bytes(read(self.bytes.as_ref(), self.weights() + 8 * position))
+    }
+    fn targets(&self) -> usize {
+        HEADER_SIZE + 8 * (self.vertex_count + 1)
+    }
+    fn weights(&self) -> usize {
+        self.targets() + 8 * self.edge_count
+    }
+    fn size(&self) -> Option<usize> {
+        let numbers = self
+            .vertex_count
+            .checked_add(1)?
+            .checked_add(self.edge_count.checked_mul(1 + self.weighted as usize)?)?;
+        numbers
+            .checked_mul(8)?
+            .checked_add(HEADER_SIZE + CHECKSUM_SIZE)
+    }
+}
+
+/// Opens a file in the binary format via a read-only memory map
+///
+/// The graph is available in constant time, its content is only loaded from disk when it is accessed.
+///
+/// # Errors
+///
+/// Returns `Err` if the file cannot be opened or mapped or if it does not contain a valid header.
+#[cfg(feature = "mmap")]
+pub fn open(path: impl AsRef<std::path::Path>) -> Result<BinaryGraph<memmap2::Mmap>, Error> {
+    let file = std::fs::File::open(path)?;
+    // SAFETY: The map is read-only. Modifying the file while it is mapped results in undefined
+    // behaviour, which cannot be prevented here and has to be ensured by the caller.
+    let map = unsafe { memmap2::Mmap::map(&file)? };
+    BinaryGraph::from_bytes(map)
+}
+
+fn read<const N: usize>(content: &[u8], position: usize) -> [u8; N] {
+    content[position..position + N].try_into().unwrap()
+}
+fn read_u32(content: &[u8], position: usize) -> u32 {
+    u32::from_le_bytes(read(content, position))
+}
+fn read_u64(content: &[u8], position: usize) -> u64 {
+    u64::from_le_bytes(read(content, position))
+}
+
+/// 64 bit FNV-1a hash
+struct Checksum(u64);
+impl Checksum {
+    fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+struct ChecksumWriter<W> {
+    writer: W,
+    checksum: Checksum,
+}
+impl<W: Write> ChecksumWriter<W> {
+    fn from(writer: W) -> Self {
+        Self {
+            writer,
+            checksum: Checksum::new(),
+        }
+    }
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.checksum.update(bytes);
+        self.writer.write_all(bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes_of(graph: &Graph) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write(graph, &mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn reads_written_graph() {
+        let graph = Graph::from(4, vec![(0, 1), (0, 3), (0, 1), (2, 2)]).unwrap();
+        let binary = BinaryGraph::from_bytes(bytes_of(&graph)).unwrap();
+        assert!(binary.verify().is_ok());
+        assert_eq!(binary.vertex_count(), 4);
+        assert!(!binary.is_weighted());
+        assert_eq!(
+            binary.out_neighbors(VertexId(0)).collect::<Vec<VertexId>>(),
+            vec![VertexId(1), VertexId(3), VertexId(1)]
+        );
+        assert_eq!(
+            binary.out_neighbors(VertexId(1)).collect::<Vec<VertexId>>(),
+            vec![]
+        );
+        assert!(binary.out_weights(VertexId(0)).is_none());
+        assert_eq!(binary.to_graph().unwrap(), graph);
+    }
+
+    #[test]
+    fn reads_written_weighted_graph() {
+        let graph = Graph::from_weighted(3, vec![(2, 0, 1.5), (0, 1, -1.0), (2, 1, 3.0)]).unwrap();
+        let binary = BinaryGraph::from_bytes(bytes_of(&graph)).unwrap();
+        assert!(binary.verify().is_ok());
+        assert!(binary.is_weighted());
+        assert_eq!(
+            binary
+                .out_weights(VertexId(2))
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            vec![1.5, 3.0]
+        );
+        assert_eq!(
+            binary.to_graph().unwrap(),
+            Graph::from_weighted(3, vec![(0, 1, -1.0), (2, 0, 1.5), (2, 1, 3.0)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_order_of_sorted_adjacency() {
+        let graph = Graph::from_weighted(3, vec![(0, 2, 1.0), (1, 0, 2.0), (0, 1, 3.0)])
+            .unwrap()
+            .with_sorted_adjacency();
+        let binary = BinaryGraph::from_bytes(bytes_of(&graph)).unwrap();
+        assert_eq!(
+            binary.out_neighbors(VertexId(0)).collect::<Vec<VertexId>>(),
+            graph.out_neighbors(VertexId(0)).collect::<Vec<VertexId>>()
+        );
+        assert_eq!(
+            binary
+                .out_weights(VertexId(0))
+                .unwrap()
+                .collect::<Vec<f64>>(),
+            vec![3.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn gives_counts_and_edges() {
+        let graph = Graph::from(4, vec![(3, 0), (0, 1), (0, 3), (0, 1)]).unwrap();
+        let binary = BinaryGraph::from_bytes(bytes_of(&graph)).unwrap();
+        assert_eq!(binary.edge_count(), 4);
+        assert_eq!(binary.out_degree(&VertexId(0)), 3);
+        assert_eq!(binary.out_degree(&VertexId(2)), 0);
+        assert!(binary.contains(&VertexId(3)));
+        assert!(!binary.contains(&VertexId(4)));
+        assert_eq!(
+            binary.vertices().collect::<Vec<VertexId>>(),
+            graph.vertices().cloned().collect::<Vec<VertexId>>()
+        );
+        assert_eq!(
+            binary.edges().collect::<Vec<Edge>>(),
+            graph
+                .vertices()
+                .flat_map(|vertex| graph.out_edges(vertex).map(|(_, edge)| edge.clone()))
+                .collect::<Vec<Edge>>()
+        );
+    }
+
+    #[test]
+    fn reads_empty_graph() {
+        let graph = Graph::from(0, vec![]).unwrap();
+        let binary = BinaryGraph::from_bytes(bytes_of(&graph)).unwrap();
+        assert!(binary.verify().is_ok());
+        assert_eq!(binary.to_graph().unwrap(), graph);
+    }
+
+    #[test]
+    fn rejects_invalid_bytes() {
+        assert!(matches!(
+            BinaryGraph::from_bytes(b"no graph".to_vec()),
+            Err(Error::Corrupted(_))
+        ));
+
+        let bytes = bytes_of(&Graph::from(2, vec![(0, 1)]).unwrap());
+
+        let mut other_version = bytes.clone();
+        other_version[8] = 2;
+        assert!(matches!(
+            BinaryGraph::from_bytes(other_version),
+            Err(Error::Unsupported(_))
+        ));
+
+        let truncated = bytes[..bytes.len() - 1].to_vec();
+        assert!(matches!(
+            BinaryGraph::from_bytes(truncated),
+            Err(Error::Corrupted(_))
+        ));
+
+        let mut modified = bytes.clone();
+        modified[HEADER_SIZE + 3 * 8] = 0;
+        let binary = BinaryGraph::from_bytes(modified).unwrap();
+        assert!(matches!(binary.verify(), Err(Error::Corrupted(_))));
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn opens_memory_mapped_file() {
+        let graph = Graph::from_weighted(3, vec![(0, 1, 1.0), (1, 2, 2.0)]).unwrap();
+        let path = std::env::temp_dir().join(format!("graph-{}.bin", std::process::id()));
+        std::fs::write(&path, bytes_of(&graph)).unwrap();
+
+        let binary = open(&path).unwrap();
+        assert!(binary.verify().is_ok());
+        assert_eq!(binary.to_graph().unwrap(), graph);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
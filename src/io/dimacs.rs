@@ -0,0 +1,363 @@
+//! DIMACS shortest path (`.gr`) and maximum flow (`.max`) formats
+//!
+//! Both formats describe a weighted graph line by line: Comment lines start with `c`, the problem line `p <problem> <vertex count> <arc count>` comes before all other lines and each arc is given as `a <from> <to> <weight>` with vertices counted from 1. For the shortest path problem the weight is the length of the arc, for the maximum flow problem it is its capacity. A maximum flow file additionally designates a source and a sink vertex with `n <vertex> s` and `n <vertex> t`.
+use std::io::{BufRead, Write};
+
+use super::{parse_number, parse_vertex, Error};
+use crate::graph::{Graph, VertexId};
+
+/// A graph whose edge weights are capacities together with the vertices between which a flow is sent
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlowNetwork {
+    pub graph: Graph,
+    pub source: VertexId,
+    pub sink: VertexId,
+}
+
+/// Reads a shortest path problem
+///
+/// The arc lengths become the weights of the graph.
+///
+/// # Errors
+///
+/// Returns `Err` if the content is not a valid DIMACS shortest path problem.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::dimacs, Graph};
+///
+/// let content = "c a comment
+/// p sp 3 2
+/// a 1 2 4
+/// a 2 3 1
+/// ";
+///
+/// assert_eq!(
+///     dimacs::read_shortest_path(content.as_bytes()).unwrap(),
+///     Graph::from_weighted(3, vec![(0, 1, 4.0), (1, 2, 1.0)]).unwrap()
+/// );
+/// ```
+pub fn read_shortest_path(reader: impl BufRead) -> Result<Graph, Error> {
+    let problem = read(reader, "sp")?;
+    if !problem.designations.is_empty() {
+        return Err(Error::Unsupported(
+            "vertex designations in a shortest path problem".to_string(),
+        ));
+    }
+    Ok(problem.graph)
+}
+
+/// Reads a maximum flow problem
+///
+/// The arc capacities become the weights of the graph.
+///
+/// # Errors
+///
+/// Returns `Err` if the content is not a valid DIMACS maximum flow problem, including a missing source or sink.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::dimacs::{self, FlowNetwork}, Graph, VertexId};
+///
+/// let content = "p max 2 1
+/// n 1 s
+/// n 2 t
+/// a 1 2 5
+/// ";
+///
+/// assert_eq!(
+///     dimacs::read_max_flow(content.as_bytes()).unwrap(),
+///     FlowNetwork {
+///         graph: Graph::from_weighted(2, vec![(0, 1, 5.0)]).unwrap(),
+///         source: VertexId(0),
+///         sink: VertexId(1),
+///     }
+/// );
+/// ```
+pub fn read_max_flow(reader: impl BufRead) -> Result<FlowNetwork, Error> {
+    let problem = read(reader, "max")?;
+    let designated = |designation: &str| {
+        let mut vertices = problem
+            .designations
+            .iter()
+            .filter(|(_, d)| d == designation)
+            .map(|(v, _)| v);
+        match (vertices.next(), vertices.next()) {
+            (Some(vertex), None) => Ok(vertex.clone()),
+            (None, _) => Err(Error::InvalidGraph(format!(
+                "vertex designation {designation} is missing"
+            ))),
+            (Some(_), Some(_)) => Err(Error::InvalidGraph(format!(
+                "vertex designation {designation} is given more than once"
+            ))),
+        }
+    };
+    let (source, sink) = (designated("s")?, designated("t")?);
+    Ok(FlowNetwork {
+        graph: problem.graph,
+        source,
+        sink,
+    })
+}
+
+struct Problem {
+    graph: Graph,
+    designations: Vec<(VertexId, String)>,
+}
+
+fn read(reader: impl BufRead, expected_problem: &str) -> Result<Problem, Error> {
+    let mut size = None;
+    let mut edges = Vec::new();
+    let mut designations = Vec::new();
+    for (number, line) in reader.lines().enumerate().map(|(i, l)| (i + 1, l)) {
+        let line = line?;
+        // the rest of a comment line is arbitrary, it does not have to be separated from the c
+        if line.trim_start().starts_with('c') {
+            continue;
+        }
+        let mut tokens = line.split_whitespace();
+        match (tokens.next(), size) {
+            (None, _) => (),
+            (Some("p"), None) => {
+                let problem = tokens.next();
+                if problem != Some(expected_problem) {
+                    return Err(Error::parse(
+                        number,
+                        format!("expected problem {expected_problem}"),
+                    ));
+                }
+                let vertex_count: usize = parse_number(tokens.next(), number, "vertex count")?;
+                let arc_count: usize = parse_number(tokens.next(), number, "arc count")?;
+                size = Some((vertex_count, arc_count, number));
+            }
+            (Some("p"), Some(_)) => {
+                return Err(Error::parse(number, "problem line is given twice"));
+            }
+            (Some(_), None) => {
+                return Err(Error::parse(number, "problem line is missing"));
+            }
+            (Some("a"), Some(_)) => {
+                let from = parse_vertex(tokens.next(), number)?;
+                let to = parse_vertex(tokens.next(), number)?;
+                let weight: f64 = parse_number(tokens.next(), number, "arc weight")?;
+                edges.push((from, to, weight));
+            }
+            (Some("n"), Some(_)) => {
+                let vertex = parse_vertex(tokens.next(), number)?;
+                let designation = match tokens.next() {
+                    Some(designation @ ("s" | "t")) => designation,
+                    Some(other) => {
+                        return Err(Error::parse(
+                            number,
+                            format!("unknown vertex designation {other}"),
+                        ))
+                    }
+                    None => return Err(Error::parse(number, "vertex designation is missing")),
+                };
+                designations.push((VertexId(vertex), designation.to_string()));
+            }
+            (Some(other), Some(_)) => {
+                return Err(Error::parse(number, format!("unknown line type {other}")));
+            }
+        }
+    }
+
+    let (vertex_count, arc_count, problem_line) =
+        size.ok_or(Error::parse(1, "problem line is missing"))?;
+    if edges.len() != arc_count {
+        return Err(Error::parse(
+            problem_line,
+            format!("expected {arc_count} arcs but found {}", edges.len()),
+        ));
+    }
+    if let Some((vertex, _)) = designations.iter().find(|(v, _)| v.0 >= vertex_count) {
+        return Err(Error::InvalidGraph(format!(
+            "designated vertex {} does not exist",
+            vertex.0 + 1
+        )));
+    }
+    Ok(Problem {
+        graph: Graph::from_weighted(vertex_count, edges).map_err(Error::InvalidGraph)?,
+        designations,
+    })
+}
+
+/// Writes a graph as shortest path problem
+///
+/// The weights of the graph are written as arc lengths, an unweighted graph gets length 1 for all arcs.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::dimacs, Graph};
+///
+/// let mut content = Vec::new();
+/// dimacs::write_shortest_path(&Graph::from(2, vec![(1, 0)]).unwrap(), &mut content).unwrap();
+///
+/// assert_eq!(String::from_utf8(content).unwrap(), "p sp 2 1\na 2 1 1\n");
+/// ```
+pub fn write_shortest_path(graph: &Graph, mut writer: impl Write) -> Result<(), Error> {
+    write_problem_line(graph, "sp", &mut writer)?;
+    write_arcs(graph, &mut writer)
+}
+
+/// Writes a flow network as maximum flow problem
+///
+/// The weights of the graph are written as arc capacities, an unweighted graph gets capacity 1 for all arcs.
+///
+/// # Errors
+///
+/// Returns `Err` if the source or the sink is not part of the graph, then nothing is written.
+pub fn write_max_flow(network: &FlowNetwork, mut writer: impl Write) -> Result<(), Error> {
+    for (vertex, name) in [(&network.source, "source"), (&network.sink, "sink")] {
+        if !network.graph.contains(vertex) {
+            return Err(Error::InvalidGraph(format!(
+                "{name} {} does not exist",
+                vertex.0 + 1
+            )));
+        }
+    }
+    write_problem_line(&network.graph, "max", &mut writer)?;
+    writeln!(writer, "n {} s", network.source.0 + 1)?;
+    writeln!(writer, "n {} t", network.sink.0 + 1)?;
+    write_arcs(&network.graph, &mut writer)
+}
+
+fn write_problem_line(graph: &Graph, problem: &str, writer: &mut impl Write) -> Result<(), Error> {
+    writeln!(
+        writer,
+        "p {problem} {} {}",
+        graph.vertices().count(),
+        graph.edges().count()
+    )?;
+    Ok(())
+}
+
+fn write_arcs(graph: &Graph, writer: &mut impl Write) -> Result<(), Error> {
+    for (i, edge) in graph.edges().enumerate() {
+        let weight = graph.weights().map_or(1.0, |weights| weights[i]);
+        writeln!(writer, "a {} {} {weight}", edge.0 .0 + 1, edge.1 .0 + 1)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_shortest_path_problem() {
+        let content = "c 9th DIMACS Implementation Challenge\n\
+                       c\n\
+                       c9th comment without a space\n\
+                       p sp 4 3\n\
+                       c graph contains 4 nodes and 3 arcs\n\
+                       a 1 2 803\n\
+                       a 2 1 803\n\
+                       \n\
+                       a 4 4 0\n";
+        assert_eq!(
+            read_shortest_path(content.as_bytes()).unwrap(),
+            Graph::from_weighted(4, vec![(0, 1, 803.0), (1, 0, 803.0), (3, 3, 0.0)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn reads_max_flow_problem() {
+        let content = "c max flow problem\n\
+                       p max 3 2\n\
+                       n 3 t\n\
+                       n 1 s\n\
+                       a 1 2 10\n\
+                       a 2 3 4\n";
+        assert_eq!(
+            read_max_flow(content.as_bytes()).unwrap(),
+            FlowNetwork {
+                graph: Graph::from_weighted(3, vec![(0, 1, 10.0), (1, 2, 4.0)]).unwrap(),
+                source: VertexId(0),
+                sink: VertexId(2)
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_content() {
+        assert!(matches!(
+            read_shortest_path("a 1 2 3\n".as_bytes()),
+            Err(Error::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            read_shortest_path("p max 2 0\n".as_bytes()),
+            Err(Error::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            read_shortest_path("p sp 2 1\nx 1 2\n".as_bytes()),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            read_shortest_path("p sp 2 2\na 1 2 1\n".as_bytes()),
+            Err(Error::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            read_shortest_path("p sp 2 1\na 1 3 1\n".as_bytes()),
+            Err(Error::InvalidGraph(_))
+        ));
+        assert!(matches!(
+            read_max_flow("p max 2 0\nn 1 s\n".as_bytes()),
+            Err(Error::InvalidGraph(_))
+        ));
+        assert!(matches!(
+            read_max_flow("p max 2 0\nn 1 s\nn 2 s\nn 2 t\n".as_bytes()),
+            Err(Error::InvalidGraph(_))
+        ));
+        assert!(matches!(
+            read_max_flow("p max 2 0\nn 1 s\nn 3 t\n".as_bytes()),
+            Err(Error::InvalidGraph(_))
+        ));
+        assert!(matches!(
+            read_max_flow("p max 2 0\nn 1 s\nn 2 t\nn 2 x\n".as_bytes()),
+            Err(Error::Parse { line: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_writing_missing_source_or_sink() {
+        for (source, sink) in [(VertexId(2), VertexId(1)), (VertexId(0), VertexId(5))] {
+            let network = FlowNetwork {
+                graph: Graph::from_weighted(2, vec![(0, 1, 3.0)]).unwrap(),
+                source,
+                sink,
+            };
+            let mut content = Vec::new();
+            assert!(matches!(
+                write_max_flow(&network, &mut content),
+                Err(Error::InvalidGraph(_))
+            ));
+            assert!(content.is_empty());
+        }
+    }
+
+    #[test]
+    fn written_problems_can_be_read_again() {
+        let graph = Graph::from_weighted(3, vec![(0, 1, 2.0), (2, 1, 7.0), (2, 1, 1.0)]).unwrap();
+        let mut content = Vec::new();
+        write_shortest_path(&graph, &mut content).unwrap();
+        assert_eq!(read_shortest_path(content.as_slice()).unwrap(), graph);
+
+        let network = FlowNetwork {
+            graph: Graph::from_weighted(2, vec![(0, 1, 3.0)]).unwrap(),
+            source: VertexId(0),
+            sink: VertexId(1),
+        };
+        let mut content = Vec::new();
+        write_max_flow(&network, &mut content).unwrap();
+        assert_eq!(
+            String::from_utf8(content.clone()).unwrap(),
+            "p max 2 1\nn 1 s\nn 2 t\na 1 2 3\n"
+        );
+        assert_eq!(read_max_flow(content.as_slice()).unwrap(), network);
+    }
+}
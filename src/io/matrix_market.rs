@@ -0,0 +1,300 @@
+//! Matrix Market coordinate format (`.mtx`)
+//!
+//! A sparse matrix is read as a graph: Entry (i, j) of the matrix is an edge from vertex i - 1 to vertex j - 1. The number of vertices is the larger one of the row and column count.
+//!
+//! Supported are the fields `pattern` (unweighted graph), `real` and `integer` (weighted graph) together with the symmetries `general` and `symmetric`. A symmetric matrix only stores its lower triangle, each off-diagonal entry therefore results in an edge in both directions.
+use std::io::{BufRead, Write};
+
+use super::{parse_number, parse_vertex, Error};
+use crate::graph::Graph;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Field {
+    Pattern,
+    Weighted,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Symmetry {
+    General,
+    Symmetric,
+}
+
+/// Reads a graph from a Matrix Market coordinate file
+///
+/// # Errors
+///
+/// Returns `Err` if the content is not a valid Matrix Market coordinate file, uses an unsupported field or symmetry or if an entry lies outside of the given matrix size.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::matrix_market, Graph};
+///
+/// let content = "%%MatrixMarket matrix coordinate pattern general
+/// % a comment
+/// 3 3 2
+/// 1 2
+/// 3 1
+/// ";
+///
+/// assert_eq!(
+///     matrix_market::read(content.as_bytes()).unwrap(),
+///     Graph::from(3, vec![(0, 1), (2, 0)]).unwrap()
+/// );
+/// ```
+pub fn read(reader: impl BufRead) -> Result<Graph, Error> {
+    let mut lines = reader.lines().enumerate().map(|(i, l)| (i + 1, l));
+
+    let (field, symmetry) = match lines.next() {
+        Some((number, line)) => read_header(&line?, number)?,
+        None => return Err(Error::parse(1, "header is missing")),
+    };
+
+    let mut size = None;
+    let mut read_entries = 0;
+    let mut edges = Vec::new();
+    let mut weights = Vec::new();
+    for (number, line) in lines {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+        let mut tokens = line.split_whitespace();
+        match size {
+            None => {
+                let rows: usize = parse_number(tokens.next(), number, "row count")?;
+                let columns: usize = parse_number(tokens.next(), number, "column count")?;
+                let entries: usize = parse_number(tokens.next(), number, "entry count")?;
+                if symmetry == Symmetry::Symmetric && rows != columns {
+                    return Err(Error::parse(number, "symmetric matrix has to be square"));
+                }
+                size = Some((rows, columns, entries, number));
+            }
+            Some((rows, columns, _, _)) => {
+                let from = parse_vertex(tokens.next(), number)?;
+                let to = parse_vertex(tokens.next(), number)?;
+                if from >= rows || to >= columns {
+                    return Err(Error::parse(number, "entry lies outside of the matrix"));
+                }
+                let weight = match field {
+                    Field::Pattern => 1.0,
+                    Field::Weighted => parse_number(tokens.next(), number, "value")?,
+                };
+                read_entries += 1;
+                edges.push((from, to));
+                weights.push(weight);
+                if symmetry == Symmetry::Symmetric && from != to {
+                    edges.push((to, from));
+                    weights.push(weight);
+                }
+            }
+        }
+    }
+
+    let (rows, columns, entries, size_line) =
+        size.ok_or(Error::parse(1, "size line is missing"))?;
+    if read_entries != entries {
+        return Err(Error::parse(
+            size_line,
+            format!("expected {entries} entries but found {read_entries}"),
+        ));
+    }
+
+    let vertex_count = rows.max(columns);
+    match field {
+        Field::Pattern => Graph::from(vertex_count, edges),
+        Field::Weighted => Graph::from_weighted(
+            vertex_count,
+            edges
+                .into_iter()
+                .zip(weights)
+                .map(|((from, to), weight)| (from, to, weight))
+                .collect(),
+        ),
+    }
+    .map_err(Error::InvalidGraph)
+}
+
+fn read_header(line: &str, number: usize) -> Result<(Field, Symmetry), Error> {
+    let tokens = line
+        .split_whitespace()
+        .map(|t| t.to_lowercase())
+        .collect::<Vec<String>>();
+    match tokens.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+        ["%%matrixmarket", "matrix", format, field, symmetry] => {
+            if format != "coordinate" {
+                return Err(Error::Unsupported(format!("format {format}")));
+            }
+            let field = match field {
+                "pattern" => Field::Pattern,
+                "real" | "integer" => Field::Weighted,
+                _ => return Err(Error::Unsupported(format!("field {field}"))),
+            };
+            let symmetry = match symmetry {
+                "general" => Symmetry::General,
+                "symmetric" => Symmetry::Symmetric,
+                _ => return Err(Error::Unsupported(format!("symmetry {symmetry}"))),
+            };
+            Ok((field, symmetry))
+        }
+        _ => Err(Error::parse(number, "invalid Matrix Market header")),
+    }
+}
+
+/// Writes a graph as Matrix Market coordinate file
+///
+/// An unweighted graph is written with field `pattern`, a weighted graph with field `real`. The symmetry is always `general`.
+///
+/// # Examples
+///
+/// ```
+/// use graph::{io::matrix_market, Graph};
+///
+/// let mut content = Vec::new();
+/// matrix_market::write(&Graph::from(2, vec![(0, 1)]).unwrap(), &mut content).unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(content).unwrap(),
+///     "%%MatrixMarket matrix coordinate pattern general\n2 2 1\n1 2\n"
+/// );
+/// ```
+pub fn write(graph: &Graph, mut writer: impl Write) -> Result<(), Error> {
+    let vertex_count = graph.vertices().count();
+    let field = match graph.weights() {
+        None => "pattern",
+        Some(_) => "real",
+    };
+    writeln!(writer, "%%MatrixMarket matrix coordinate {field} general")?;
+    writeln!(
+        writer,
+        "{vertex_count} {vertex_count} {}",
+        graph.edges().count()
+    )?;
+    for (i, edge) in graph.edges().enumerate() {
+        match graph.weights() {
+            None => writeln!(writer, "{} {}", edge.0 .0 + 1, edge.1 .0 + 1)?,
+            Some(weights) => {
+                writeln!(writer, "{} {} {}", edge.0 .0 + 1, edge.1 .0 + 1, weights[i])?
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_pattern_matrix() {
+        let content = "%%MatrixMarket matrix coordinate pattern general\n\
+                       %comment\n\
+                       \n\
+                       4 4 3\n\
+                       1 2\n\
+                       2 4\n\
+                       4 4\n";
+        assert_eq!(
+            read(content.as_bytes()).unwrap(),
+            Graph::from(4, vec![(0, 1), (1, 3), (3, 3)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn reads_weighted_matrix() {
+        let content = "%%MatrixMarket matrix coordinate real general\n\
+                       2 2 2\n\
+                       1 2 0.5\n\
+                       2 1 -3e2\n";
+        assert_eq!(
+            read(content.as_bytes()).unwrap(),
+            Graph::from_weighted(2, vec![(0, 1, 0.5), (1, 0, -300.0)]).unwrap()
+        );
+
+        let content = "%%MatrixMarket matrix coordinate integer general\n\
+                       2 2 1\n\
+                       1 2 7\n";
+        assert_eq!(
+            read(content.as_bytes()).unwrap(),
+            Graph::from_weighted(2, vec![(0, 1, 7.0)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn symmetric_entries_result_in_edges_in_both_directions() {
+        let content = "%%MatrixMarket matrix coordinate integer symmetric\n\
+                       3 3 2\n\
+                       2 1 4\n\
+                       3 3 5\n";
+        assert_eq!(
+            read(content.as_bytes()).unwrap(),
+            Graph::from_weighted(3, vec![(1, 0, 4.0), (0, 1, 4.0), (2, 2, 5.0)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn rectangular_matrix_uses_larger_dimension_as_vertex_count() {
+        let content = "%%MatrixMarket matrix coordinate pattern general\n\
+                       2 3 1\n\
+                       1 3\n";
+        assert_eq!(
+            read(content.as_bytes()).unwrap(),
+            Graph::from(3, vec![(0, 2)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_content() {
+        assert!(matches!(
+            read("".as_bytes()),
+            Err(Error::Parse { line: 1, .. })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n1 1\n1.0\n".as_bytes()),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate complex general\n".as_bytes()),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate pattern hermitian\n".as_bytes()),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate pattern general\n2 2 1\n3 1\n".as_bytes()),
+            Err(Error::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate pattern general\n2 2 1\n0 1\n".as_bytes()),
+            Err(Error::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1\n".as_bytes()),
+            Err(Error::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 1\n".as_bytes()),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn written_graph_can_be_read_again() {
+        let graph = Graph::from(3, vec![(0, 1), (2, 0), (2, 0), (1, 1)]).unwrap();
+        let mut content = Vec::new();
+        write(&graph, &mut content).unwrap();
+        assert_eq!(read(content.as_slice()).unwrap(), graph);
+
+        let graph = Graph::from_weighted(2, vec![(0, 1, 2.5), (1, 0, -1.0)]).unwrap();
+        let mut content = Vec::new();
+        write(&graph, &mut content).unwrap();
+        assert_eq!(
+            String::from_utf8(content.clone()).unwrap(),
+            "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 2 2.5\n2 1 -1\n"
+        );
+        assert_eq!(read(content.as_slice()).unwrap(), graph);
+    }
+}
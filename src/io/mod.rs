@@ -0,0 +1,76 @@
+//! Reading and writing graphs in standard file formats
+//!
+//! The text formats are used to load published benchmark instances into a [`Graph`](crate::Graph) and to write graphs back such that other tools can use them. All readers take a [`BufRead`](std::io::BufRead) and all writers a [`Write`](std::io::Write), files have to be opened by the caller.
+//!
+//! The [`binary`] format is meant for huge graphs that should be available without parsing.
+pub mod adjacency_matrix;
+pub mod binary;
+pub mod dimacs;
+pub mod matrix_market;
+
+use std::{fmt, io};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The content of the given line (counting from 1) does not follow the format
+    Parse {
+        line: usize,
+        message: String,
+    },
+    /// The content follows the format but uses a feature that is not supported
+    Unsupported(String),
+    /// The content describes a graph that cannot be created, e.g. because of dangling edges
+    InvalidGraph(String),
+    /// The binary content is inconsistent, e.g. because it was truncated or modified
+    Corrupted(String),
+}
+impl Error {
+    fn parse(line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{error}"),
+            Error::Parse { line, message } => write!(f, "line {line}: {message}"),
+            Error::Unsupported(message) => write!(f, "unsupported: {message}"),
+            Error::InvalidGraph(message) => write!(f, "invalid graph: {message}"),
+            Error::Corrupted(message) => write!(f, "corrupted: {message}"),
+        }
+    }
+}
+impl std::error::Error for Error {}
+
+/// Parses a 1-based vertex number of a file into a 0-based vertex index
+fn parse_vertex(token: Option<&str>, line: usize) -> Result<usize, Error> {
+    match token.map(|t| t.parse::<usize>()) {
+        Some(Ok(vertex)) if vertex > 0 => Ok(vertex - 1),
+        Some(Ok(_)) => Err(Error::parse(line, "vertex numbers start at 1")),
+        Some(Err(_)) => Err(Error::parse(
+            line,
+            "vertex number is not a positive integer",
+        )),
+        None => Err(Error::parse(line, "vertex number is missing")),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(
+    token: Option<&str>,
+    line: usize,
+    name: &str,
+) -> Result<T, Error> {
+    token
+        .ok_or_else(|| Error::parse(line, format!("{name} is missing")))?
+        .parse::<T>()
+        .map_err(|_| Error::parse(line, format!("{name} is not a valid number")))
+}
@@ -1,18 +1,51 @@
 pub mod algorithms;
+pub mod dense;
 pub mod graph;
+pub mod io;
+mod random;
 
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
 pub struct ReadmeDoctests;
 
-pub use crate::algorithms::component::Component;
+pub use crate::algorithms::chordal::{is_chordal, perfect_elimination_ordering};
+pub use crate::algorithms::component::{Component, Partition};
+pub use crate::algorithms::enumeration::basic::deepening::IterativeDeepening;
+pub use crate::algorithms::enumeration::basic::distances::{Distances, Reached};
 pub use crate::algorithms::enumeration::basic::graph::BreadthFirst as BreadthFirstOnGraph;
 pub use crate::algorithms::enumeration::basic::graph::DepthFirst as DepthFirstOnGraph;
+pub use crate::algorithms::enumeration::basic::lexicographic::{
+    LexBreadthFirst as LexBreadthFirstOnGraph,
+    MaximumCardinalitySearch as MaximumCardinalitySearchOnGraph,
+};
 pub use crate::algorithms::enumeration::basic::tree::BreadthFirst as BreadthFirstOnTree;
 pub use crate::algorithms::enumeration::basic::tree::DepthFirst as DepthFirstOnTree;
-pub use crate::algorithms::enumeration::detailed::graph::DepthFirst as DetailedDepthFirstOnGraph;
+pub use crate::algorithms::enumeration::basic::tree::{
+    BestFirst as BestFirstOnTree, Checkpoint, ZeroOneBreadthFirst as ZeroOneBreadthFirstOnTree,
+};
+pub use crate::algorithms::enumeration::basic::tree::{
+    RandomBreadthFirst as RandomBreadthFirstOnTree, RandomDepthFirst as RandomDepthFirstOnTree,
+};
+pub use crate::algorithms::enumeration::detailed::graph::{
+    BreadthFirst as DetailedBreadthFirstOnGraph, DepthFirst as DetailedDepthFirstOnGraph,
+};
 pub use crate::algorithms::enumeration::detailed::tree::{
-    DFSEntry, DepthFirst as DetailedDepthFirstOnTree,
+    BreadthFirst as DetailedBreadthFirstOnTree, BufferedVertex, Checkpoint as DetailedCheckpoint,
+    ClassifiedEntry, DFSEntry, DepthFirst as DetailedDepthFirstOnTree, EdgeKind,
 };
+pub use crate::algorithms::enumeration::detailed::visitor::{Control, Visitor};
+pub use crate::algorithms::enumeration::filter::RejectedVertex;
+pub use crate::algorithms::enumeration::order::NeighborOrder;
+pub use crate::algorithms::enumeration::paths::{Path, Paths, Uniqueness};
 pub use crate::algorithms::scc::algorithm::SCC;
+pub use crate::algorithms::scc::condensation::{condensation, Condensation};
+pub use crate::algorithms::scc::incremental::IncrementalSCC;
+pub use crate::algorithms::scc::iterative::IterativeSCC;
+pub use crate::algorithms::scc::kosaraju::KosarajuSCC;
+pub use crate::algorithms::scc::path_based::PathBasedSCC;
+pub use crate::algorithms::scc::StronglyConnectedComponents;
+pub use crate::algorithms::walks::RandomWalks;
+pub use crate::algorithms::wcc::disjoint_set::{DisjointSet, RollbackDisjointSet};
+pub use crate::algorithms::wcc::incremental::IncrementalWCC;
+pub use crate::dense::DenseGraph;
 pub use crate::graph::{Edge, Graph, VertexId};
@@ -0,0 +1,133 @@
+//! Seedable pseudo random number generator for randomized algorithms
+//!
+//! The generator is xoshiro256** (see <https://prng.di.unimi.it>), its state is initialized from the seed with splitmix64. It is fast and has good statistical properties but is not suitable for cryptography. The same seed always gives the same sequence of numbers, such that randomized algorithms are reproducible.
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: [u64; 4],
+}
+impl Rng {
+    pub fn seed_from(seed: u64) -> Self {
+        let mut splitmix = seed;
+        let mut next = || {
+            splitmix = splitmix.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = splitmix;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        Self {
+            state: [next(), next(), next(), next()],
+        }
+    }
+
+    /// Continues the sequence of numbers of the generator whose [`Rng::state`] is given
+    pub fn from_state(state: [u64; 4]) -> Self {
+        Self { state }
+    }
+    pub fn state(&self) -> [u64; 4] {
+        self.state
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+        result
+    }
+
+    /// Gives a uniformly distributed number in `0..bound`
+    ///
+    /// Numbers from the incomplete last block of the u64 range are rejected, such that all numbers are equally likely.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "bound has to be positive");
+        let bound = bound as u64;
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % bound) as usize;
+            }
+        }
+    }
+
+    /// Gives a uniformly distributed number in `[0, 1)`
+    ///
+    /// The 53 upper bits of a random number are used as the mantissa, such that all numbers are multiples of 2^-53.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Shuffles the items uniformly with the Fisher-Yates algorithm
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        let mut first = Rng::seed_from(42);
+        let mut second = Rng::seed_from(42);
+        let mut other = Rng::seed_from(43);
+        let numbers = (0..10).map(|_| first.next_u64()).collect::<Vec<u64>>();
+        assert_eq!(
+            numbers,
+            (0..10).map(|_| second.next_u64()).collect::<Vec<u64>>()
+        );
+        assert_ne!(
+            numbers,
+            (0..10).map(|_| other.next_u64()).collect::<Vec<u64>>()
+        );
+    }
+
+    #[test]
+    fn continues_from_state() {
+        let mut rng = Rng::seed_from(42);
+        rng.next_u64();
+        let mut restored = Rng::from_state(rng.state());
+        assert_eq!(
+            (0..10).map(|_| rng.next_u64()).collect::<Vec<u64>>(),
+            (0..10).map(|_| restored.next_u64()).collect::<Vec<u64>>()
+        );
+    }
+
+    #[test]
+    fn gives_all_numbers_below_bound() {
+        let mut rng = Rng::seed_from(0);
+        let mut counts = [0; 5];
+        for _ in 0..1000 {
+            counts[rng.below(5)] += 1;
+        }
+        assert!(counts.iter().all(|&count| count > 150));
+    }
+
+    #[test]
+    fn gives_floats_in_unit_interval() {
+        let mut rng = Rng::seed_from(1);
+        let numbers = (0..1000).map(|_| rng.next_f64()).collect::<Vec<f64>>();
+        assert!(numbers.iter().all(|&number| (0.0..1.0).contains(&number)));
+        let mean = numbers.iter().sum::<f64>() / 1000.0;
+        assert!((0.45..0.55).contains(&mean));
+    }
+
+    #[test]
+    fn shuffles_into_a_permutation() {
+        let mut rng = Rng::seed_from(7);
+        let mut items = (0..20).collect::<Vec<usize>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<usize>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<usize>>());
+    }
+}